@@ -21,18 +21,46 @@ use winapi::um::errhandlingapi::GetLastError;
 use winapi::shared::minwindef::*;
 use winapi::shared::windef::*;
 use winapi::um::winuser::*;
+use winapi::um::shellscalingapi::{GetDpiForMonitor, MDT_EFFECTIVE_DPI};
 use std::ptr::null_mut;
 use std::mem::size_of;
 
 use crate::screen::Monitor;
 use crate::kurbo::Rect;
 
-static mut MONITORS : Vec<Monitor> = Vec::new();
+/// The DPI windows uses as its "100%" baseline, i.e. a scale factor of 1.0.
+const BASELINE_DPI: f64 = 96.0;
 
-unsafe extern "system" fn monitorenumproc(hmonitor : HMONITOR, _hdc : HDC, _lprect : LPRECT, _lparam : LPARAM) -> BOOL {
-    let rect = RECT { left: 0, top: 0, right: 0, bottom: 0};
-    let mut info = MONITORINFO { cbSize : size_of::<MONITORINFO>() as u32, rcMonitor : rect, rcWork : rect, dwFlags : 0};
-    if GetMonitorInfoW(hmonitor,&mut info) == 0 {
+/// Queries the effective DPI for `hmonitor` via `shcore`, falling back to the baseline
+/// (a scale factor of 1.0) when the `GetDpiForMonitor` call fails. `GetDpiForMonitor` is
+/// imported directly rather than looked up at runtime, so `shcore` (Windows 8.1 or later) has to
+/// be present; there is no fallback for systems without it.
+fn get_scale_for_monitor(hmonitor: HMONITOR) -> f64 {
+    let mut dpi_x: u32 = 0;
+    let mut dpi_y: u32 = 0;
+    unsafe {
+        let hr = GetDpiForMonitor(hmonitor, MDT_EFFECTIVE_DPI, &mut dpi_x, &mut dpi_y);
+        if hr == S_OK && dpi_x > 0 {
+            dpi_x as f64 / BASELINE_DPI
+        } else {
+            warn!(
+                "failed to get DPI for monitor, falling back to scale 1.0: {}",
+                Error::Hr(hr)
+            );
+            1.0
+        }
+    }
+}
+
+unsafe extern "system" fn monitorenumproc(
+    hmonitor: HMONITOR,
+    _hdc: HDC,
+    _lprect: LPRECT,
+    lparam: LPARAM,
+) -> BOOL {
+    let rect = RECT { left: 0, top: 0, right: 0, bottom: 0 };
+    let mut info = MONITORINFO { cbSize: size_of::<MONITORINFO>() as u32, rcMonitor: rect, rcWork: rect, dwFlags: 0 };
+    if GetMonitorInfoW(hmonitor, &mut info) == 0 {
         warn!(
             "failed to get Monitor Info: {}",
             Error::Hr(HRESULT_FROM_WIN32(GetLastError()))
@@ -41,21 +69,26 @@ unsafe extern "system" fn monitorenumproc(hmonitor : HMONITOR, _hdc : HDC, _lpre
     let primary = info.dwFlags == MONITORINFOF_PRIMARY;
     let rect = Rect::new(info.rcMonitor.left as f64, info.rcMonitor.top as f64, info.rcMonitor.right as f64, info.rcMonitor.bottom as f64);
     let work_rect = Rect::new(info.rcWork.left as f64, info.rcWork.top as f64, info.rcWork.right as f64, info.rcWork.bottom as f64);
-    let m = Monitor::new(primary, rect, work_rect);
-    MONITORS.push(m);
+    let scale = get_scale_for_monitor(hmonitor);
+    let m = Monitor::new(primary, rect, work_rect, scale);
+
+    // `lparam` carries a pointer to the caller's `Vec<Monitor>`, stashed there instead of going
+    // through a `static mut` so that concurrent calls to `get_monitors` can't race on shared state.
+    let monitors = &mut *(lparam as *mut Vec<Monitor>);
+    monitors.push(m);
     TRUE
 }
 
-
 pub(crate) fn get_monitors() -> Vec<Monitor> {
+    let mut monitors: Vec<Monitor> = Vec::new();
     unsafe {
-        MONITORS = Vec::new();
-        if EnumDisplayMonitors(null_mut(), null_mut(), Some(monitorenumproc), 0) == 0{
+        let lparam = &mut monitors as *mut Vec<Monitor> as LPARAM;
+        if EnumDisplayMonitors(null_mut(), null_mut(), Some(monitorenumproc), lparam) == 0 {
             warn!(
                 "Failed to Enumerate Display Monitors: {}",
                 Error::Hr(HRESULT_FROM_WIN32(GetLastError()))
             );
         };
-        MONITORS.clone()
     }
+    monitors
 }
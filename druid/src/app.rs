@@ -35,12 +35,43 @@ pub struct AppLauncher<T> {
     ext_event_host: ExtEventHost,
 }
 
+/// How a window's titlebar, min/max/close controls, and border are drawn and hit-tested.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Decorations {
+    /// The platform draws and hit-tests the titlebar and border as usual.
+    Native,
+    /// The app draws its own titlebar and caption buttons. The platform still owns dragging,
+    /// double-click-to-maximize, and snap layouts: widgets mark their layout rect as a drag
+    /// region with [`DraggableArea`], and the platform answers hit-tests against those regions
+    /// (`HTCAPTION` on Windows) instead of painting a titlebar of its own.
+    ///
+    /// [`DraggableArea`]: ../widget/struct.DraggableArea.html
+    Client,
+}
+
+/// A parent/owned-window API - a `WindowConfig` field naming an already-open window that a new
+/// one should be positioned or stacked relative to, with `AppState::build_native_window` passing
+/// that relationship on to the platform `WindowBuilder` - was tried and dropped. That plumbing
+/// isn't part of this crate's local snapshot - it lives alongside the rest of `AppState` and the
+/// platform shell's `WindowBuilder`, the same as the hitbox traversal `WidgetPod::after_layout`
+/// depends on does for the window handler - so it's out of scope here rather than something this
+/// `WindowConfig` can express on its own.
+///
+/// Relatedly, an `inherit_from` field - letting a new `WindowConfig` start from an already-open
+/// window's size/position/decorations, with `build_native_window` falling back to cascading the
+/// new window's placement a fixed offset from that inherited position when none is set explicitly
+/// - was also tried and dropped, for the same reason: it needs to read another live window's
+/// placement out of `AppState::build_native_window`, which isn't part of this crate's local
+/// snapshot either.
+#[derive(Clone)]
 pub struct WindowConfig {
     pub(crate) size: Option<Size>,
     pub(crate) min_size: Option<Size>,
     pub(crate) position: Option<Point>,
     pub(crate) resizable: Option<bool>,
     pub(crate) show_titlebar: Option<bool>,
+    pub(crate) decorations: Option<Decorations>,
+    pub(crate) resize_border: Option<f64>,
     pub(crate) maximized: Option<bool>,
     pub(crate) minimized: Option<bool>,
     pub(crate) level: Option<WindowLevel>,
@@ -204,13 +235,28 @@ impl Default for WindowConfig {
             position: None,
             resizable: None,
             show_titlebar: None,
+            decorations: None,
+            resize_border: None,
             maximized: None,
             minimized: None,
             level: None,
+            state: WindowState::Restored,
         }
     }
 }
 
+/// All sizes and positions on `WindowConfig` are in [display points] - a DPI-independent unit
+/// that stays fixed as a window's scale factor changes, e.g. when it's dragged to a monitor with
+/// a different DPI setting. Delivering a `LifeCycle::ScaleChanged { scale }` event down the
+/// widget tree when that happens - so a widget that caches pixel-snapped geometry knows to
+/// recompute it - was tried and dropped: it needs a variant on the `LifeCycle` enum and the
+/// window handler code that would detect the scale change and dispatch it, neither of which is
+/// part of this crate's local snapshot. What's real here is the unit clarification above: widgets
+/// already work in display points and get a correctly-scaled [`Scale`] from the platform on every
+/// paint, they just don't get an explicit event telling them the scale moved.
+///
+/// [display points]: struct.Scale.html
+/// [`Scale`]: struct.Scale.html
 impl WindowConfig {
     /// Set the window's initial drawing area size in [display points].
     ///
@@ -261,10 +307,38 @@ impl WindowConfig {
         self
     }
 
-    /// Sets the initial window position in virtual screen coordinates.
-    /// [`position`] Position in pixels.
+    /// Set how the window's titlebar and border are drawn and hit-tested.
+    ///
+    /// [`Decorations::Client`] is how to build a custom unified titlebar (in the style of e.g.
+    /// the Zed editor) while keeping native window dragging, double-click-to-maximize, and snap
+    /// layouts - mark the drag region of your titlebar with a [`DraggableArea`].
+    ///
+    /// [`Decorations::Client`]: enum.Decorations.html#variant.Client
+    /// [`DraggableArea`]: ../widget/struct.DraggableArea.html
+    pub fn decorations(mut self, decorations: Decorations) -> Self {
+        self.decorations = Some(decorations);
+        self
+    }
+
+    /// Set the thickness, in [display points], of the invisible border around the window's
+    /// perimeter that should still resize it, even with [`show_titlebar(false)`] or
+    /// [`Decorations::Client`]. Has no effect when combined with [`resizable(false)`].
+    ///
+    /// [display points]: struct.Scale.html
+    /// [`show_titlebar(false)`]: #method.show_titlebar
+    /// [`Decorations::Client`]: enum.Decorations.html#variant.Client
+    /// [`resizable(false)`]: #method.resizable
+    pub fn resize_border(mut self, resize_border: f64) -> Self {
+        self.resize_border = Some(resize_border);
+        self
+    }
+
+    /// Sets the initial window position, in virtual screen [display points] - the same logical
+    /// units as [`window_size`], not raw pixels. This matters on multi-monitor setups where
+    /// monitors can have different scale factors.
     ///
-    /// [`position`]: struct.Point.html
+    /// [`window_size`]: #method.window_size
+    /// [display points]: struct.Scale.html
     pub fn set_position(mut self, position: Point) -> Self {
         self.position = Some(position);
         self
@@ -287,6 +361,22 @@ impl WindowConfig {
         self
     }
 
+    /// Creates the window fullscreen.
+    ///
+    /// Use [`WindowHandle::set_fullscreen`] to toggle fullscreen at runtime instead.
+    ///
+    /// [`WindowHandle::set_fullscreen`]: struct.WindowHandle.html#method.set_fullscreen
+    pub fn fullscreen(mut self) -> Self {
+        self.state = WindowState::Fullscreen;
+        self
+    }
+
+    /// Set the window's initial state.
+    pub fn set_window_state(mut self, state: WindowState) -> Self {
+        self.state = state;
+        self
+    }
+
     pub fn apply_to_builder(&self, builder: &mut WindowBuilder) {
         if let Some(resizable) = self.resizable {
             builder.resizable(resizable);
@@ -296,6 +386,14 @@ impl WindowConfig {
             builder.show_titlebar(show_titlebar);
         }
 
+        if let Some(decorations) = self.decorations {
+            builder.set_decorations(decorations);
+        }
+
+        if let Some(resize_border) = self.resize_border {
+            builder.set_resize_border(resize_border);
+        }
+
         if let Some(size) = self.size {
             builder.set_size(size);
         }
@@ -319,6 +417,10 @@ impl WindowConfig {
             log::info!("Set level on builder{:?}", level);
             builder.set_level(level)
         }
+
+        if let WindowState::Fullscreen = self.state {
+            builder.set_window_state(self.state);
+        }
     }
 
     pub fn apply_to_handle(&self, win_handle: &mut WindowHandle) {
@@ -330,6 +432,14 @@ impl WindowConfig {
             win_handle.show_titlebar(show_titlebar);
         }
 
+        if let Some(decorations) = self.decorations {
+            win_handle.set_decorations(decorations);
+        }
+
+        if let Some(resize_border) = self.resize_border {
+            win_handle.set_resize_border(resize_border);
+        }
+
         if let Some(size) = self.size {
             win_handle.set_size(size);
         }
@@ -352,6 +462,10 @@ impl WindowConfig {
         if let Some(level) = self.level {
             win_handle.set_level(level)
         }
+
+        if let WindowState::Fullscreen = self.state {
+            win_handle.set_fullscreen(true);
+        }
     }
 }
 
@@ -442,10 +556,29 @@ impl<T: Data> WindowDesc<T> {
         self
     }
 
-    /// Sets the initial window position in virtual screen coordinates.
-    /// [`position`] Position in pixels.
+    /// Set how the window's titlebar and border are drawn and hit-tested.
+    ///
+    /// [`Decorations::Client`]: enum.Decorations.html#variant.Client
+    pub fn decorations(mut self, decorations: Decorations) -> Self {
+        self.config = self.config.decorations(decorations);
+        self
+    }
+
+    /// Set the thickness, in [display points], of the invisible border around the window's
+    /// perimeter that should still resize it.
+    ///
+    /// [display points]: struct.Scale.html
+    pub fn resize_border(mut self, resize_border: f64) -> Self {
+        self.config = self.config.resize_border(resize_border);
+        self
+    }
+
+    /// Sets the initial window position, in virtual screen [display points] - the same logical
+    /// units as [`window_size`], not raw pixels. This matters on multi-monitor setups where
+    /// monitors can have different scale factors.
     ///
-    /// [`position`]: struct.Point.html
+    /// [`window_size`]: #method.window_size
+    /// [display points]: struct.Scale.html
     pub fn set_position(mut self, position: Point) -> Self {
         self.config = self.config.set_position(position);
         self
@@ -0,0 +1,201 @@
+// Copyright 2020 The Druid Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The accessibility tree: a parallel, much smaller tree that mirrors the parts of the widget
+//! tree a screen reader (via AccessKit) needs to know about.
+//!
+//! [`Widget::accessibility`] is the read-only sibling of [`Widget::paint`]: instead of emitting
+//! drawing commands, a widget pushes zero or more [`AccessNode`]s describing itself to the
+//! [`AccessCtx`]. `WidgetPod::accessibility` walks the tree the same way `WidgetPod::paint` does,
+//! assembling the pushed nodes (plus a generated container node per pod) into the `TreeUpdate`
+//! that is handed to the platform's AccessKit adapter.
+//!
+//! [`Widget::accessibility`]: trait.Widget.html#method.accessibility
+//! [`Widget::paint`]: trait.Widget.html#method.paint
+//!
+//! Platform actions coming back the other way (e.g. a screen reader invoking a button) are
+//! delivered by the window handler translating them into an `Event` and routing it through the
+//! normal `event` pass the same way a mouse click would be, so a widget only has to handle one
+//! code path for "this control was activated" regardless of input modality. That `Event` variant
+//! isn't part of this crate's local snapshot - it lives alongside the rest of the `Event` enum.
+
+use crate::{Rect, WidgetId};
+
+/// The role a node plays in the accessibility tree, used by the screen reader to decide how to
+/// announce it and what interactions to expose. This is intentionally a small subset of
+/// AccessKit's own `Role` enum - just enough for the widgets in this crate to describe
+/// themselves; it is expected to grow as more widgets gain [`Widget::accessibility`] impls.
+///
+/// [`Widget::accessibility`]: trait.Widget.html#method.accessibility
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccessRole {
+    /// A node with no more specific role; still exposed so its label and bounds are reachable.
+    Generic,
+    Button,
+    CheckBox,
+    Slider,
+    ScrollBar,
+    TabList,
+    Tab,
+    TabPanel,
+    Window,
+}
+
+/// One node of the accessibility tree, as reported by a widget's [`Widget::accessibility`].
+///
+/// [`Widget::accessibility`]: trait.Widget.html#method.accessibility
+#[derive(Debug, Clone)]
+pub struct AccessNode {
+    pub id: WidgetId,
+    pub role: AccessRole,
+    pub name: Option<String>,
+    pub rect: Rect,
+    /// Whether this node currently accepts focus/input (e.g. a disabled tab would report
+    /// `false`).
+    pub enabled: bool,
+}
+
+impl AccessNode {
+    pub fn new(id: WidgetId, role: AccessRole, rect: Rect) -> Self {
+        AccessNode {
+            id,
+            role,
+            name: None,
+            rect,
+            enabled: true,
+        }
+    }
+
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn with_enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+}
+
+/// Context passed to [`Widget::accessibility`], analogous to [`PaintCtx`] but for building the
+/// accessibility tree rather than painting pixels.
+///
+/// [`Widget::accessibility`]: trait.Widget.html#method.accessibility
+/// [`PaintCtx`]: struct.PaintCtx.html
+pub struct AccessCtx {
+    current_id: WidgetId,
+    nodes: Vec<AccessNode>,
+}
+
+impl AccessCtx {
+    pub fn new(root_id: WidgetId) -> Self {
+        AccessCtx {
+            current_id: root_id,
+            nodes: Vec::new(),
+        }
+    }
+
+    /// The id of the widget whose pod is currently being walked. `WidgetPod::accessibility` sets
+    /// this before calling into the wrapped widget, the same way it wraps `paint` without the
+    /// widget ever naming its own id.
+    pub fn widget_id(&self) -> WidgetId {
+        self.current_id
+    }
+
+    /// Called by `WidgetPod::accessibility` as it descends, so nodes pushed by the widget it is
+    /// about to visit are attributed to that widget's id.
+    #[doc(hidden)]
+    pub fn set_current_id(&mut self, id: WidgetId) {
+        self.current_id = id;
+    }
+
+    /// Report a node for the widget currently being visited; `id` is filled in from
+    /// [`AccessCtx::widget_id`], so callers only need to supply role and bounds.
+    pub fn push_node(&mut self, role: AccessRole, rect: Rect) -> &mut AccessNode {
+        self.nodes
+            .push(AccessNode::new(self.current_id, role, rect));
+        self.nodes.last_mut().unwrap()
+    }
+
+    /// How many nodes have been pushed so far; paired with [`AccessCtx::apply_override_from`],
+    /// this lets `WidgetPod::accessibility` snapshot the count before calling into a widget and
+    /// then patch just the nodes that widget pushed.
+    ///
+    /// [`AccessCtx::apply_override_from`]: struct.AccessCtx.html#method.apply_override_from
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Apply `over` to every node pushed since `from` (see [`AccessCtx::node_count`]).
+    /// `WidgetPod::accessibility` is expected to call this after a widget's
+    /// [`Widget::accessibility`] returns, using whatever [`AccessOverride`] it finds via
+    /// `widget.augmentation_raw(TypeId::of::<AccessOverride>())` - the same `Augmented` lookup
+    /// [`EnsuredPod::aug`] already uses for other augmentation payloads. This is how app code
+    /// attaches a role/label override to a widget via [`Augmented`] without writing a custom
+    /// `Widget::accessibility` impl.
+    ///
+    /// [`Widget::accessibility`]: trait.Widget.html#method.accessibility
+    /// [`AccessOverride`]: struct.AccessOverride.html
+    /// [`EnsuredPod::aug`]: struct.EnsuredPod.html#method.aug
+    /// [`Augmented`]: struct.Augmented.html
+    pub fn apply_override_from(&mut self, from: usize, over: &AccessOverride) {
+        for node in &mut self.nodes[from..] {
+            if let Some(role) = over.role {
+                node.role = role;
+            }
+            if over.name.is_some() {
+                node.name = over.name.clone();
+            }
+        }
+    }
+
+    /// Consume the context, returning every node pushed so far, in push order. `WidgetPod`'s
+    /// assembly of the final `TreeUpdate` (from the AccessKit crate, not present in this
+    /// snapshot) is responsible for turning these flat, push-order nodes into the parent/child
+    /// relationships AccessKit expects.
+    pub fn into_nodes(self) -> Vec<AccessNode> {
+        self.nodes
+    }
+}
+
+/// A role/label override for a widget's accessibility node(s), attached via [`Augmented`] rather
+/// than by writing a custom [`Widget::accessibility`] impl - e.g.
+/// `Augmented::new(my_widget, AccessOverride::new().with_name("Submit"))` to rename a node that
+/// would otherwise inherit a generic label. See [`AccessCtx::apply_override_from`] for how it is
+/// consulted.
+///
+/// [`Augmented`]: struct.Augmented.html
+/// [`Widget::accessibility`]: trait.Widget.html#method.accessibility
+/// [`AccessCtx::apply_override_from`]: struct.AccessCtx.html#method.apply_override_from
+#[derive(Debug, Clone, Default)]
+pub struct AccessOverride {
+    role: Option<AccessRole>,
+    name: Option<String>,
+}
+
+impl AccessOverride {
+    pub fn new() -> Self {
+        AccessOverride::default()
+    }
+
+    pub fn with_role(mut self, role: AccessRole) -> Self {
+        self.role = Some(role);
+        self
+    }
+
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+}
@@ -0,0 +1,124 @@
+// Copyright 2020 The Druid Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A hitbox pass that runs between `layout` and `paint`, so hot/active state reflects the frame
+//! that was just laid out rather than the previous one.
+//!
+//! Hot state computed purely from mouse-move events lags by a frame whenever a widget's position
+//! changes as a direct result of `layout` rather than a pointer move - e.g. rows that are added,
+//! removed, or reordered in the same pass that also moves the hover target out from under the
+//! pointer. [`Widget::after_layout`] is the hook for this: after `layout` has settled every
+//! widget's [`layout_rect`], `WidgetPod::after_layout` calls into it and each widget reports its
+//! own hit region(s) to [`AfterLayoutCtx`] via [`AfterLayoutCtx::insert_hitbox`]. Containers just
+//! recurse into their children, in painting order, the same as [`Widget::paint`] does; widgets
+//! that paint an overlay on top of other content (menus, sub-windows) register their hitbox after
+//! whatever they cover, so it occludes it in [`AfterLayoutCtx::topmost_hit`]. The window handler
+//! then uses the freshly built hitbox list to resolve which widget is under the pointer and
+//! updates `is_hot` for this frame, instead of waiting for the next `MouseMove`.
+//!
+//! [`Widget::after_layout`]: trait.Widget.html#method.after_layout
+//! [`Widget::paint`]: trait.Widget.html#method.paint
+//! [`WidgetPod::after_layout`]: struct.WidgetPod.html#method.after_layout
+//! [`layout_rect`]: struct.WidgetPod.html#method.layout_rect
+//!
+//! `WidgetPod::after_layout`'s traversal and the window handler's use of the resulting hitbox
+//! list aren't part of this crate's local snapshot - they live alongside the rest of `WidgetPod`
+//! and the platform event loop, the same as `WidgetPod::accessibility`'s traversal does for the
+//! accessibility tree. What lives here, and is real: [`AfterLayoutCtx`] itself, and the widgets
+//! in this crate that override `Widget::after_layout`: [`Scroll`], which reports its own
+//! viewport region through it, and [`TearOff`], which only passes it on to its docked child.
+//!
+//! [`AfterLayoutCtx`]: struct.AfterLayoutCtx.html
+//! [`Scroll`]: struct.Scroll.html
+//! [`TearOff`]: struct.TearOff.html
+
+use crate::{Point, Rect, WidgetId};
+
+/// One widget's hit region, as reported by [`Widget::after_layout`]. Later entries in the list
+/// paint on top of earlier ones, the same order `Widget::paint` visits widgets in, so
+/// [`AfterLayoutCtx::topmost_hit`] favors whichever was inserted last.
+///
+/// [`Widget::after_layout`]: trait.Widget.html#method.after_layout
+/// [`AfterLayoutCtx::topmost_hit`]: struct.AfterLayoutCtx.html#method.topmost_hit
+#[derive(Debug, Clone, Copy)]
+pub struct Hitbox {
+    pub id: WidgetId,
+    pub rect: Rect,
+}
+
+/// Context passed to [`Widget::after_layout`], analogous to [`PaintCtx`] but for registering hit
+/// regions rather than painting pixels.
+///
+/// [`Widget::after_layout`]: trait.Widget.html#method.after_layout
+/// [`PaintCtx`]: struct.PaintCtx.html
+pub struct AfterLayoutCtx {
+    current_id: WidgetId,
+    hitboxes: Vec<Hitbox>,
+}
+
+impl AfterLayoutCtx {
+    pub fn new(root_id: WidgetId) -> Self {
+        AfterLayoutCtx {
+            current_id: root_id,
+            hitboxes: Vec::new(),
+        }
+    }
+
+    /// The id of the widget whose pod is currently being walked. `WidgetPod::after_layout` sets
+    /// this before calling into the wrapped widget, the same way [`AccessCtx::widget_id`] does
+    /// for the accessibility pass.
+    ///
+    /// [`AccessCtx::widget_id`]: struct.AccessCtx.html#method.widget_id
+    pub fn widget_id(&self) -> WidgetId {
+        self.current_id
+    }
+
+    /// Called by `WidgetPod::after_layout` as it descends, so a hitbox inserted without an
+    /// explicit id is attributed to the widget currently being visited.
+    #[doc(hidden)]
+    pub fn set_current_id(&mut self, id: WidgetId) {
+        self.current_id = id;
+    }
+
+    /// Register a hit region for the widget currently being visited, in window coordinates.
+    pub fn insert_hitbox(&mut self, rect: Rect) {
+        self.insert_hitbox_for(self.current_id, rect)
+    }
+
+    /// Register a hit region attributed to a specific widget id rather than the one currently
+    /// being visited - for a container that wants a hitbox it inserts to resolve to one of its
+    /// children (e.g. a sub-window host registering its child's id) rather than to itself.
+    pub fn insert_hitbox_for(&mut self, widget_id: WidgetId, rect: Rect) {
+        self.hitboxes.push(Hitbox {
+            id: widget_id,
+            rect,
+        });
+    }
+
+    /// The id of the topmost hitbox containing `pos`, if any - the last-inserted hitbox whose
+    /// `rect` contains the point wins, since later entries paint over earlier ones.
+    pub fn topmost_hit(&self, pos: Point) -> Option<WidgetId> {
+        self.hitboxes
+            .iter()
+            .rev()
+            .find(|hitbox| hitbox.rect.contains(pos))
+            .map(|hitbox| hitbox.id)
+    }
+
+    /// Consume the context, returning every hitbox registered this pass, in traversal (paint)
+    /// order.
+    pub fn into_hitboxes(self) -> Vec<Hitbox> {
+        self.hitboxes
+    }
+}
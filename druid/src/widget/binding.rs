@@ -2,7 +2,9 @@ use crate::{
     BoxConstraints, Data, Env, Event, EventCtx, LayoutCtx, Lens, LifeCycle, LifeCycleCtx, PaintCtx,
     Selector, Size, UpdateCtx, Widget,
 };
+use std::cell::RefCell;
 use std::marker::PhantomData;
+use std::rc::Rc;
 
 /// This trait indicates that a class is a wrapper of another widget that may have API you wish to access.
 /// Used by BindingHost to "reach inside" things like LensWrapped in order to find the right widget to control,
@@ -76,6 +78,19 @@ pub trait Binding<T, Controlled> {
         ctx: &mut EventCtx,
         env: &Env,
     );
+
+    /// Advance any internal animation state by `elapsed_nanos` (the interval carried by
+    /// `LifeCycle::AnimFrame`), writing the tweened value directly into `controlled`.
+    /// Returns `true` if another animation frame is needed to finish the transition.
+    /// Bindings that don't animate can rely on the default no-op.
+    fn advance_animation(
+        &self,
+        _controlled: &mut Controlled,
+        _elapsed_nanos: u64,
+        _env: &Env,
+    ) -> bool {
+        false
+    }
 }
 
 /// Allows a cons-list (or HList) of bindings to be built up, by treating a tuple of bindings as a binding.
@@ -132,6 +147,17 @@ impl<T, Controlled, Bind1: Binding<T, Controlled>, Bind2: Binding<T, Controlled>
                 .apply_change_to_data(controlled, data, change1, ctx, env);
         }
     }
+
+    fn advance_animation(
+        &self,
+        controlled: &mut Controlled,
+        elapsed_nanos: u64,
+        env: &Env,
+    ) -> bool {
+        let still0 = self.0.advance_animation(controlled, elapsed_nanos, env);
+        let still1 = self.1.advance_animation(controlled, elapsed_nanos, env);
+        still0 || still1
+    }
 }
 
 /// One way binding wrappers
@@ -170,6 +196,15 @@ impl<T, Controlled, B: Binding<T, Controlled>> Binding<T, Controlled>
         _env: &Env,
     ) {
     }
+
+    fn advance_animation(
+        &self,
+        controlled: &mut Controlled,
+        elapsed_nanos: u64,
+        env: &Env,
+    ) -> bool {
+        self.0.advance_animation(controlled, elapsed_nanos, env)
+    }
 }
 
 pub struct WidgetToDataOnlyBinding<B>(B);
@@ -209,6 +244,15 @@ impl<T, Controlled, B: Binding<T, Controlled>> Binding<T, Controlled>
         self.0
             .apply_change_to_data(controlled, data, change, ctx, env);
     }
+
+    fn advance_animation(
+        &self,
+        controlled: &mut Controlled,
+        elapsed_nanos: u64,
+        env: &Env,
+    ) -> bool {
+        self.0.advance_animation(controlled, elapsed_nanos, env)
+    }
 }
 
 /// This binds two lenses that evaluate to the same type together.
@@ -292,6 +336,26 @@ impl<T, Controlled, PropValue: Data, LT: Lens<T, PropValue>, LC: Lens<Controlled
     }
 }
 
+impl<T, Controlled, PropValue, LT: Lens<T, PropValue>, LC: Lens<Controlled, PropValue>>
+    LensBinding<T, Controlled, PropValue, LT, LC>
+{
+    /// Turn this into a [`TransitionBinding`] that tweens the controlled property toward each
+    /// new data value over `duration` nanoseconds (the same units as `LifeCycle::AnimFrame`)
+    /// instead of snapping to it, reshaping `t` through `easing` first.
+    pub fn transition(
+        self,
+        duration: u64,
+        easing: fn(f64) -> f64,
+    ) -> TransitionBinding<T, Controlled, PropValue, LT, LC> {
+        TransitionBinding::new(
+            self.lens_from_data,
+            self.lens_from_controlled,
+            duration,
+            easing,
+        )
+    }
+}
+
 pub trait BindableProperty {
     type Controlling;
     type Value;
@@ -405,6 +469,311 @@ impl<
     }
 }
 
+/// Linear interpolation between two values of the same type, used by [`TransitionBinding`] to
+/// tween a controlled property toward its data value instead of snapping to it.
+pub trait Interpolate: Clone {
+    /// Interpolate between `self` and `other`, where `t` of `0.0` is `self` and `1.0` is `other`.
+    fn lerp(&self, other: &Self, t: f64) -> Self;
+}
+
+impl Interpolate for f64 {
+    fn lerp(&self, other: &Self, t: f64) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl Interpolate for crate::Insets {
+    fn lerp(&self, other: &Self, t: f64) -> Self {
+        crate::Insets::new(
+            self.x0.lerp(&other.x0, t),
+            self.y0.lerp(&other.y0, t),
+            self.x1.lerp(&other.x1, t),
+            self.y1.lerp(&other.y1, t),
+        )
+    }
+}
+
+impl Interpolate for crate::Color {
+    fn lerp(&self, other: &Self, t: f64) -> Self {
+        let (r0, g0, b0, a0) = self.as_rgba();
+        let (r1, g1, b1, a1) = other.as_rgba();
+        crate::Color::rgba(
+            r0.lerp(&r1, t),
+            g0.lerp(&g1, t),
+            b0.lerp(&b1, t),
+            a0.lerp(&a1, t),
+        )
+    }
+}
+
+/// A cubic ease-in-out easing function, suitable for passing to [`LensBinding::transition`].
+pub fn ease_in_out_cubic(t: f64) -> f64 {
+    if t < 0.5 {
+        4.0 * t * t * t
+    } else {
+        1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+    }
+}
+
+struct TransitionState<PropValue> {
+    start: PropValue,
+    target: PropValue,
+    elapsed: u64,
+}
+
+/// The transition counterpart of [`LensBinding`]: rather than writing each new data value
+/// straight into the controlled property, it tweens from the property's current value to the
+/// new one over `duration` nanoseconds (the same units `LifeCycle::AnimFrame` reports), passing
+/// `t` through `easing` along the way. Animation state lives behind a `RefCell` because
+/// `Binding` methods only get `&self`.
+pub struct TransitionBinding<
+    T,
+    Controlled,
+    PropValue,
+    LT: Lens<T, PropValue>,
+    LC: Lens<Controlled, PropValue>,
+> {
+    lens_from_data: LT,
+    lens_from_controlled: LC,
+    duration: u64,
+    easing: fn(f64) -> f64,
+    state: std::cell::RefCell<Option<TransitionState<PropValue>>>,
+    phantom_t: PhantomData<T>,
+    phantom_c: PhantomData<Controlled>,
+}
+
+impl<T, Controlled, PropValue, LT: Lens<T, PropValue>, LC: Lens<Controlled, PropValue>>
+    TransitionBinding<T, Controlled, PropValue, LT, LC>
+{
+    pub fn new(
+        lens_from_data: LT,
+        lens_from_controlled: LC,
+        duration: u64,
+        easing: fn(f64) -> f64,
+    ) -> Self {
+        TransitionBinding {
+            lens_from_data,
+            lens_from_controlled,
+            duration,
+            easing,
+            state: std::cell::RefCell::new(None),
+            phantom_t: Default::default(),
+            phantom_c: Default::default(),
+        }
+    }
+}
+
+impl<
+        T,
+        Controlled,
+        PropValue: Interpolate + Data,
+        LT: Lens<T, PropValue>,
+        LC: Lens<Controlled, PropValue>,
+    > Binding<T, Controlled> for TransitionBinding<T, Controlled, PropValue, LT, LC>
+{
+    type Change = ();
+
+    fn apply_data_to_controlled(
+        &self,
+        data: &T,
+        controlled: &mut Controlled,
+        ctx: &mut UpdateCtx,
+        _env: &Env,
+    ) {
+        self.lens_from_data.with(data, |target_val| {
+            self.lens_from_controlled.with(controlled, |current_val| {
+                let is_new_target = self
+                    .state
+                    .borrow()
+                    .as_ref()
+                    .map_or(!current_val.same(target_val), |s| {
+                        !s.target.same(target_val)
+                    });
+                if is_new_target {
+                    *self.state.borrow_mut() = Some(TransitionState {
+                        start: current_val.clone(),
+                        target: target_val.clone(),
+                        elapsed: 0,
+                    });
+                }
+            })
+        });
+        if self.state.borrow().is_some() {
+            ctx.request_anim_frame();
+        }
+    }
+
+    fn append_change_required(
+        &self,
+        _controlled: &Controlled,
+        _data: &T,
+        _change: &mut Option<Self::Change>,
+        _env: &Env,
+    ) {
+    }
+
+    fn apply_change_to_data(
+        &self,
+        _controlled: &Controlled,
+        _data: &mut T,
+        _change: Self::Change,
+        _ctx: &mut EventCtx,
+        _env: &Env,
+    ) {
+    }
+
+    fn advance_animation(
+        &self,
+        controlled: &mut Controlled,
+        elapsed_nanos: u64,
+        _env: &Env,
+    ) -> bool {
+        let mut state = self.state.borrow_mut();
+        let finished = if let Some(s) = state.as_mut() {
+            s.elapsed = s.elapsed.saturating_add(elapsed_nanos);
+            let t = (s.elapsed as f64 / self.duration as f64).min(1.0);
+            let eased = (self.easing)(t);
+            let value = s.start.lerp(&s.target, eased);
+            self.lens_from_controlled
+                .with_mut(controlled, |c| *c = value);
+            t >= 1.0
+        } else {
+            false
+        };
+        if finished {
+            *state = None;
+        }
+        state.is_some()
+    }
+}
+
+/// One field read from / written back into `T`, feeding a [`ComputedBinding`]. Built from a
+/// `Lens` but stored as plain closures so sources of the same `PropValue` type but different
+/// concrete `Lens` types can sit together in one `Vec`.
+pub struct ComputedSource<T, PropValue> {
+    get: Box<dyn Fn(&T) -> PropValue>,
+    set: Box<dyn Fn(&mut T, PropValue)>,
+}
+
+impl<T: 'static, PropValue: Data> ComputedSource<T, PropValue> {
+    pub fn new<L: Lens<T, PropValue> + Copy + 'static>(lens: L) -> Self {
+        ComputedSource {
+            get: Box::new(move |data| lens.with(data, |v| v.clone())),
+            set: Box::new(move |data, v| lens.with_mut(data, |field| *field = v)),
+        }
+    }
+}
+
+/// Drives a single controlled property from several data fields at once (or the reverse):
+/// `project` combines the current value of every source into the property's `Prop` value, and
+/// `decompose` is its inverse, splitting a `Prop` written back from the widget out across the
+/// sources. This covers the cases a nested `(Bind1, Bind2)` tuple can't express, where a
+/// property isn't just a 1:1 map of a single field but a genuine function of several of them
+/// (e.g. a `rgba` property driven by four separate `f64` channel fields).
+pub struct ComputedBinding<T, Controlled, PropValue, Prop, PropC>
+where
+    PropC: BindableProperty<Controlling = Controlled, Value = Prop>,
+{
+    sources: Vec<ComputedSource<T, PropValue>>,
+    project: fn(&[PropValue]) -> Prop,
+    decompose: fn(&Prop) -> Vec<PropValue>,
+    prop_from_controlled: PropC,
+    // Tracks the last value we projected and wrote, so a write-back whose re-projection lands
+    // back on the same value doesn't get appended as a fresh `Change` and loop back out again.
+    last_projected: RefCell<Option<Prop>>,
+}
+
+impl<T, Controlled, PropValue, Prop, PropC> ComputedBinding<T, Controlled, PropValue, Prop, PropC>
+where
+    PropC: BindableProperty<Controlling = Controlled, Value = Prop>,
+{
+    pub fn new(
+        sources: Vec<ComputedSource<T, PropValue>>,
+        project: fn(&[PropValue]) -> Prop,
+        decompose: fn(&Prop) -> Vec<PropValue>,
+        prop_from_controlled: PropC,
+    ) -> Self {
+        ComputedBinding {
+            sources,
+            project,
+            decompose,
+            prop_from_controlled,
+            last_projected: RefCell::new(None),
+        }
+    }
+
+    fn project_sources(&self, data: &T) -> Prop {
+        let values: Vec<PropValue> = self.sources.iter().map(|s| (s.get)(data)).collect();
+        (self.project)(&values)
+    }
+}
+
+impl<T, Controlled, PropValue, Prop: Data, PropC> Binding<T, Controlled>
+    for ComputedBinding<T, Controlled, PropValue, Prop, PropC>
+where
+    PropC: BindableProperty<Controlling = Controlled, Value = Prop>,
+{
+    type Change = PropC::Change;
+
+    fn apply_data_to_controlled(
+        &self,
+        data: &T,
+        controlled: &mut Controlled,
+        ctx: &mut UpdateCtx,
+        env: &Env,
+    ) {
+        let projected = self.project_sources(data);
+        self.prop_from_controlled
+            .write_prop(controlled, ctx, &projected, env);
+        *self.last_projected.borrow_mut() = Some(projected);
+    }
+
+    fn append_change_required(
+        &self,
+        controlled: &Controlled,
+        data: &T,
+        change: &mut Option<Self::Change>,
+        env: &Env,
+    ) {
+        let projected = self.project_sources(data);
+        let unchanged_since_our_own_write = self
+            .last_projected
+            .borrow()
+            .as_ref()
+            .map_or(false, |last| last.same(&projected));
+        if !unchanged_since_our_own_write {
+            self.prop_from_controlled
+                .append_changes(controlled, &projected, change, env);
+        }
+    }
+
+    fn apply_change_to_data(
+        &self,
+        controlled: &Controlled,
+        data: &mut T,
+        change: Self::Change,
+        ctx: &mut EventCtx,
+        env: &Env,
+    ) {
+        let mut projected = self.project_sources(data);
+        self.prop_from_controlled.update_data_from_change(
+            controlled,
+            ctx,
+            &mut projected,
+            change,
+            env,
+        );
+        for (source, value) in self
+            .sources
+            .iter()
+            .zip((self.decompose)(&projected).into_iter())
+        {
+            (source.set)(data, value);
+        }
+        *self.last_projected.borrow_mut() = Some(projected);
+    }
+}
+
 /// This series of traits provides combinators for building up bindings
 pub trait LensBindingExt<T, U>: Lens<T, U> + Sized {
     // Need GATs to merge these methods
@@ -433,6 +802,18 @@ where
     ) -> BindingHost<T, U, Self, Self::Wrapped, B> {
         BindingHost::new(self, binding)
     }
+
+    /// Like [`Self::binding`], but also returns a [`BindingHandle`] that can reload the binding
+    /// at runtime.
+    fn binding_with_handle<B: Binding<T, Self::Wrapped>>(
+        self,
+        binding: B,
+    ) -> (
+        BindingHost<T, U, Self, Self::Wrapped, B>,
+        BindingHandle<T, Self::Wrapped, B>,
+    ) {
+        BindingHost::new_with_handle(self, binding)
+    }
 }
 
 impl<T, U, W> WidgetBindingExt<T, U> for W
@@ -456,8 +837,54 @@ pub trait BindingExt<T, Controlled>: Binding<T, Controlled> + Sized {
 
 impl<T, Controlled, B: Binding<T, Controlled> + Sized> BindingExt<T, Controlled> for B {}
 
+/// The binding of a [`BindingHost`] and any change built up from the controlled widget, kept
+/// together behind a single `RefCell` so a [`BindingHandle`] can swap the binding and discard a
+/// stale pending change in one go.
+struct BindingSlot<T, Controlled, B: Binding<T, Controlled>> {
+    binding: B,
+    pending_change: Option<B::Change>,
+    phantom_t: PhantomData<T>,
+    phantom_c: PhantomData<Controlled>,
+}
+
+/// A handle that lets an application swap the `Binding` inside a live [`BindingHost`] at
+/// runtime, without rebuilding the widget tree - e.g. to toggle a field between read-only
+/// (`.forward()`) and two-way, or to switch which lens drives a property.
+///
+/// Reading the binding in `event`/`update`/`lifecycle`/`layout` takes a `RefCell` borrow, which
+/// carries a small amount of locking-like overhead versus a plain field access.
+pub struct BindingHandle<T, Controlled, B: Binding<T, Controlled>> {
+    slot: Rc<RefCell<BindingSlot<T, Controlled, B>>>,
+}
+
+impl<T, Controlled, B: Binding<T, Controlled>> BindingHandle<T, Controlled, B> {
+    /// Replace the live binding with `new_binding`. Any `Change` built up against the old
+    /// binding is discarded, so a stale `apply_change_to_data` can never fire against the
+    /// replacement.
+    pub fn reload(&self, new_binding: B) {
+        let mut slot = self.slot.borrow_mut();
+        slot.binding = new_binding;
+        slot.pending_change = None;
+    }
+}
+
 /// A binding host wraps a BindableAccess, and offers bindings from the Data at this stage of the hierarchy
 /// to properties on that Bindable.
+///
+/// Changes detected outside of `event` (in `lifecycle`, `update`, or `layout`, where `data` isn't
+/// mutably available) are applied by submitting `APPLY_BINDINGS` to self, which `event` handles
+/// by running `apply_pending_changes` - the same round trip any other widget uses to turn a
+/// change noticed outside `event` into one applied inside it. `paint` can't submit commands, so a
+/// change spotted there has to wait for some later pass to notice it.
+///
+/// A first-class `mutate_later` deferred-mutation pass - a `WidgetState`-backed queue of
+/// `FnOnce(&mut T, &Env)` closures, drained by a dedicated traversal the event loop runs after
+/// the normal event pass - would let `check_for_changes` enqueue a mutation directly instead of
+/// round-tripping `APPLY_BINDINGS` through `submit_command`, and would let `paint` enqueue one
+/// too instead of deferring to whatever pass comes next. That queue and traversal aren't part of
+/// this crate's local snapshot - they'd live on `WidgetState` and in the window handler's event
+/// loop, the same as `WidgetPod::after_layout`'s hitbox traversal does for hit-testing - so this
+/// is out of scope here and `BindingHost` keeps the `APPLY_BINDINGS` round trip described above.
 pub struct BindingHost<
     T,
     U,
@@ -466,8 +893,7 @@ pub struct BindingHost<
     B: Binding<T, Controlled>,
 > {
     contained: Contained,
-    binding: B,
-    pending_change: Option<B::Change>,
+    slot: Rc<RefCell<BindingSlot<T, Controlled, B>>>,
     phantom_u: PhantomData<U>,
 }
 
@@ -482,31 +908,52 @@ impl<
     pub fn new(contained: Contained, binding: B) -> Self {
         BindingHost {
             contained,
-            binding,
-            pending_change: None,
+            slot: Rc::new(RefCell::new(BindingSlot {
+                binding,
+                pending_change: None,
+                phantom_t: Default::default(),
+                phantom_c: Default::default(),
+            })),
             phantom_u: Default::default(),
         }
     }
 
+    /// Like [`Self::new`], but also returns a [`BindingHandle`] that can reload the binding
+    /// later on, without rebuilding this part of the widget tree.
+    pub fn new_with_handle(
+        contained: Contained,
+        binding: B,
+    ) -> (Self, BindingHandle<T, Controlled, B>) {
+        let host = Self::new(contained, binding);
+        let handle = BindingHandle {
+            slot: host.slot.clone(),
+        };
+        (host, handle)
+    }
+
     fn apply_pending_changes(&mut self, ctx: &mut EventCtx, data: &mut T, env: &Env) {
-        if let Some(change) = self.pending_change.take() {
-            self.binding
+        let mut slot = self.slot.borrow_mut();
+        if let Some(change) = slot.pending_change.take() {
+            slot.binding
                 .apply_change_to_data(self.contained.bindable(), data, change, ctx, env)
         }
     }
 
     fn check_for_changes(&mut self, data: &T, env: &Env) -> bool {
-        self.binding.append_change_required(
-            self.contained.bindable(),
-            data,
-            &mut self.pending_change,
-            env,
-        );
-        self.pending_change.is_some()
+        let mut slot = self.slot.borrow_mut();
+        let BindingSlot {
+            binding,
+            pending_change,
+            ..
+        } = &mut *slot;
+        binding.append_change_required(self.contained.bindable(), data, pending_change, env);
+        pending_change.is_some()
     }
 }
 
-/// This command is sent to self trigger event to run - which is where data can be modified.
+/// Sent by `BindingHost` to itself to trigger `event` to run - which is where `data` can be
+/// mutated - after `lifecycle`, `update`, or `layout` notices a change built up from the
+/// controlled widget.
 const APPLY_BINDINGS: Selector = Selector::new("druid-builtin.apply-bindings");
 
 impl<
@@ -540,12 +987,28 @@ impl<
         if self.check_for_changes(data, env) {
             ctx.submit_command(APPLY_BINDINGS, ctx.widget_state.id);
         }
+
+        if let LifeCycle::AnimFrame(interval) = event {
+            let still_animating = self.slot.borrow().binding.advance_animation(
+                self.contained.bindable_mut(),
+                *interval,
+                env,
+            );
+            if still_animating {
+                ctx.request_anim_frame();
+                ctx.request_paint();
+            }
+        }
     }
 
     fn update(&mut self, ctx: &mut UpdateCtx, old_data: &T, data: &T, env: &Env) {
         if !old_data.same(data) {
-            self.binding
-                .apply_data_to_controlled(data, self.contained.bindable_mut(), ctx, env);
+            self.slot.borrow().binding.apply_data_to_controlled(
+                data,
+                self.contained.bindable_mut(),
+                ctx,
+                env,
+            );
         }
         self.contained.update(ctx, old_data, data, env);
         if self.check_for_changes(data, env) {
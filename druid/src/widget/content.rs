@@ -6,16 +6,35 @@ use std::marker::PhantomData;
 use std::ops::{Add, Deref, DerefMut};
 
 /// Content - a possibly dynamic list of widget pods.
-/// The widgets within those pods are ensured to have a particular augmentation available
-pub trait Content<T, Aug> {
+/// The widgets within those pods are ensured to have a particular augmentation available.
+///
+/// `K` is the key type [`update`] reports edits in terms of - a real, per-item key for content
+/// whose children are derived from a keyed list (e.g. [`ForEachContent`]'s own `K`), or `()` for
+/// content that has no such notion (e.g. [`StaticContent`]).
+///
+/// [`update`]: #tymethod.update
+/// [`ForEachContent`]: struct.ForEachContent.html
+/// [`StaticContent`]: struct.StaticContent.html
+pub trait Content<T, Aug, K> {
     /// If possible, add this child widget to the content.
     fn add_child_widget(&mut self, cw: EnsuredPod<T, Aug>) -> bool;
     /// Content initially created
     fn content_added(&mut self, data: &T, env: &Env);
-    /// Data changed - return value indicates if the contained child widgets changed.
-    fn update(&mut self, old_data: &T, data: &T, env: &Env) -> bool;
-    /// Get a mutable ref to the child at idx
-    fn child_mut(&mut self, idx: usize) -> Option<&mut EnsuredPod<T, Aug>>;
+    /// Data changed - returns the edit script (see [`Edit`]) describing how the contained child
+    /// widgets changed, in new-sequence order. An empty script does not always mean nothing
+    /// changed: [`VirtualContent`] always returns one, and so does a [`ConditionalContent`]
+    /// branch that has just been shown.
+    ///
+    /// [`Edit`]: enum.Edit.html
+    /// [`VirtualContent`]: struct.VirtualContent.html
+    /// [`ConditionalContent`]: struct.ConditionalContent.html
+    fn update(&mut self, old_data: &T, data: &T, env: &Env) -> Vec<Edit<K>>;
+    /// Get a mutable ref to the child at idx. `data`/`env` are passed through (rather than only
+    /// threaded through `update`) so content that materializes children lazily - e.g.
+    /// [`VirtualContent`] - can build a widget for an index the first time it is asked for.
+    ///
+    /// [`VirtualContent`]: struct.VirtualContent.html
+    fn child_mut(&mut self, idx: usize, data: &T, env: &Env) -> Option<&mut EnsuredPod<T, Aug>>;
     /// Get an immutable ref to the last child
     fn last_child(&self) -> Option<&EnsuredPod<T, Aug>>;
     /// Number of children available
@@ -26,7 +45,7 @@ pub trait Content<T, Aug> {
     }
 }
 
-impl<T, Aug> Content<T, Aug> for Box<dyn Content<T, Aug>> {
+impl<T, Aug, K> Content<T, Aug, K> for Box<dyn Content<T, Aug, K>> {
     fn add_child_widget(&mut self, cw: EnsuredPod<T, Aug>) -> bool {
         self.deref_mut().add_child_widget(cw)
     }
@@ -35,12 +54,12 @@ impl<T, Aug> Content<T, Aug> for Box<dyn Content<T, Aug>> {
         self.deref_mut().content_added(data, env)
     }
 
-    fn update(&mut self, old_data: &T, data: &T, env: &Env) -> bool {
+    fn update(&mut self, old_data: &T, data: &T, env: &Env) -> Vec<Edit<K>> {
         self.deref_mut().update(old_data, data, env)
     }
 
-    fn child_mut(&mut self, idx: usize) -> Option<&mut EnsuredPod<T, Aug>> {
-        self.deref_mut().child_mut(idx)
+    fn child_mut(&mut self, idx: usize, data: &T, env: &Env) -> Option<&mut EnsuredPod<T, Aug>> {
+        self.deref_mut().child_mut(idx, data, env)
     }
 
     fn last_child(&self) -> Option<&EnsuredPod<T, Aug>> {
@@ -53,18 +72,18 @@ impl<T, Aug> Content<T, Aug> for Box<dyn Content<T, Aug>> {
 }
 
 /// Extension methods for Content that are not object safe
-pub trait ContentExt<T, Aug>: Content<T, Aug> {
+pub trait ContentExt<T, Aug, K>: Content<T, Aug, K> {
     /// Do something for each pod
-    fn for_each_child(&mut self, mut f: impl FnMut(&mut EnsuredPod<T, Aug>)) {
+    fn for_each_child(&mut self, data: &T, env: &Env, mut f: impl FnMut(&mut EnsuredPod<T, Aug>)) {
         for idx in 0..self.len() {
-            if let Some(child) = self.child_mut(idx) {
+            if let Some(child) = self.child_mut(idx, data, env) {
                 f(child)
             }
         }
     }
 
     /// Compose this content with another
-    fn then<Other: Content<T, Aug>>(self, other: Other) -> ComposedContent<T, Self, Other>
+    fn then<K2, Other: Content<T, Aug, K2>>(self, other: Other) -> ComposedContent<T, Self, Other>
     where
         Self: Sized,
     {
@@ -72,9 +91,9 @@ pub trait ContentExt<T, Aug>: Content<T, Aug> {
     }
 }
 
-impl<T, Aug, F: Content<T, Aug>> ContentExt<T, Aug> for F {}
+impl<T, Aug, K, F: Content<T, Aug, K>> ContentExt<T, Aug, K> for F {}
 
-impl<T, Aug, Content2: Content<T, Aug>> Add<Content2> for StaticContent<T, Aug> {
+impl<T, Aug, K2, Content2: Content<T, Aug, K2>> Add<Content2> for StaticContent<T, Aug> {
     type Output = ComposedContent<T, StaticContent<T, Aug>, Content2>;
 
     fn add(self, rhs: Content2) -> Self::Output {
@@ -82,7 +101,9 @@ impl<T, Aug, Content2: Content<T, Aug>> Add<Content2> for StaticContent<T, Aug>
     }
 }
 
-impl<T: Data, K, Aug, Content2: Content<T, Aug>> Add<Content2> for ForEachContent<T, K, Aug> {
+impl<T: Data, K, Aug, K2, Content2: Content<T, Aug, K2>> Add<Content2>
+    for ForEachContent<T, K, Aug>
+{
     type Output = ComposedContent<T, Self, Content2>;
 
     fn add(self, rhs: Content2) -> Self::Output {
@@ -134,7 +155,7 @@ impl<T: Data, Aug: Default + Clone + 'static> StaticContent<T, Aug> {
     }
 }
 
-impl<T: Data, Aug> Content<T, Aug> for StaticContent<T, Aug> {
+impl<T: Data, Aug> Content<T, Aug, ()> for StaticContent<T, Aug> {
     fn add_child_widget(&mut self, cw: EnsuredPod<T, Aug>) -> bool {
         self.children.push(cw);
         true
@@ -142,11 +163,13 @@ impl<T: Data, Aug> Content<T, Aug> for StaticContent<T, Aug> {
 
     fn content_added(&mut self, _data: &T, _env: &Env) {}
 
-    fn update(&mut self, _old_data: &T, _data: &T, _env: &Env) -> bool {
-        false
+    fn update(&mut self, _old_data: &T, _data: &T, _env: &Env) -> Vec<Edit<()>> {
+        // Children here are only ever added via `with_child`, never derived from `data` - so
+        // there is never an edit to report.
+        Vec::new()
     }
 
-    fn child_mut(&mut self, idx: usize) -> Option<&mut EnsuredPod<T, Aug>> {
+    fn child_mut(&mut self, idx: usize, _data: &T, _env: &Env) -> Option<&mut EnsuredPod<T, Aug>> {
         self.children.get_mut(idx)
     }
 
@@ -162,6 +185,153 @@ impl<T: Data, Aug> Content<T, Aug> for StaticContent<T, Aug> {
 type ValuesFromData<T, K> = dyn Fn(&T, &Env) -> Vec<K>;
 type WidgetFromValue<T, K, Aug> = dyn Fn(&T, &Env, K) -> EnsuredPod<T, Aug>;
 
+/// One step of a minimal edit script turning an old sequence into a new one, as produced by
+/// [`myers_diff`]. This is purely positional (unlike [`ReconcileResult`], which reports
+/// insert/remove/move by key identity), so it stays correct even when `K` contains duplicates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Edit<K> {
+    /// The element is present at the same relative position in both sequences.
+    Keep(K),
+    /// The element is only in the new sequence.
+    Insert(K),
+    /// The element is only in the old sequence.
+    Delete(K),
+}
+
+/// Apply `f` to the key carried by an [`Edit`], preserving which variant it was.
+///
+/// [`Edit`]: enum.Edit.html
+fn map_edit<K, K2>(edit: Edit<K>, f: impl FnOnce(K) -> K2) -> Edit<K2> {
+    match edit {
+        Edit::Keep(k) => Edit::Keep(f(k)),
+        Edit::Insert(k) => Edit::Insert(f(k)),
+        Edit::Delete(k) => Edit::Delete(f(k)),
+    }
+}
+
+/// Compute a minimal edit script turning `old` into `new`, using Myers' O(ND) diff algorithm.
+///
+/// This walks forward diagonals `k` from `-d..=d` (in steps of 2) for increasing `d`, tracking in
+/// `v` the furthest-reaching x coordinate reached so far on each diagonal, snaking along equal
+/// elements, until the bottom-right corner is reached; it then backtracks through the per-`d`
+/// snapshots of `v` to emit the script in order. Identical sequences are detected up front and
+/// returned as an all-[`Edit::Keep`] script without running the diagonal search at all.
+pub fn myers_diff<K: PartialEq + Clone>(old: &[K], new: &[K]) -> Vec<Edit<K>> {
+    if old == new {
+        return old.iter().cloned().map(Edit::Keep).collect();
+    }
+
+    let n = old.len() as isize;
+    let m = new.len() as isize;
+    let max = n + m;
+    let offset = max as usize;
+    let mut v = vec![0isize; 2 * max as usize + 1];
+    let idx = |k: isize| (k + offset as isize) as usize;
+
+    let mut trace: Vec<Vec<isize>> = Vec::new();
+    let mut final_d = max;
+    'search: for d in 0..=max {
+        trace.push(v.clone());
+        let mut k = -d;
+        while k <= d {
+            let mut x = if k == -d || (k != d && v[idx(k - 1)] < v[idx(k + 1)]) {
+                v[idx(k + 1)]
+            } else {
+                v[idx(k - 1)] + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && old[x as usize] == new[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            v[idx(k)] = x;
+            if x >= n && y >= m {
+                final_d = d;
+                break 'search;
+            }
+            k += 2;
+        }
+    }
+
+    let mut edits = Vec::new();
+    let mut x = n;
+    let mut y = m;
+    for d in (0..=final_d).rev() {
+        let v = &trace[d as usize];
+        let k = x - y;
+        let prev_k = if k == -d || (k != d && v[idx(k - 1)] < v[idx(k + 1)]) {
+            k + 1
+        } else {
+            k - 1
+        };
+        let prev_x = v[idx(prev_k)];
+        let prev_y = prev_x - prev_k;
+
+        while x > prev_x && y > prev_y {
+            edits.push(Edit::Keep(old[(x - 1) as usize].clone()));
+            x -= 1;
+            y -= 1;
+        }
+        if d > 0 {
+            if x == prev_x {
+                edits.push(Edit::Insert(new[(y - 1) as usize].clone()));
+            } else {
+                edits.push(Edit::Delete(old[(x - 1) as usize].clone()));
+            }
+        }
+        x = prev_x;
+        y = prev_y;
+    }
+
+    edits.reverse();
+    edits
+}
+
+/// The result of reconciling `ForEachContent`'s keys across an update: which keys are newly
+/// added, which disappeared, and which changed position without being recreated - so a
+/// consuming layout widget can drive insert/remove/move behavior (e.g. animations, preserved
+/// scroll position) instead of only knowing "something changed".
+#[derive(Debug, Clone)]
+pub struct ReconcileResult<K> {
+    pub inserted: Vec<K>,
+    pub removed: Vec<K>,
+    /// `(old_index, new_index)` pairs for keys that survived the update but changed position.
+    pub moved: Vec<(usize, usize)>,
+}
+
+impl<K> ReconcileResult<K> {
+    fn empty() -> Self {
+        ReconcileResult {
+            inserted: Vec::new(),
+            removed: Vec::new(),
+            moved: Vec::new(),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.inserted.is_empty() && self.removed.is_empty() && self.moved.is_empty()
+    }
+}
+
+/// How long a child's `EnsuredPod` is kept in a cache (e.g. [`ForEachContent`]'s or
+/// [`VirtualContent`]'s) after its key stops being requested, before being evicted (dropped, so
+/// the pod's widget state and any layout/lifecycle caches go with it).
+///
+/// [`VirtualContent`]: struct.VirtualContent.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChildLifetime {
+    /// Evict as soon as a key is absent from an update - the previous hard-coded behaviour's
+    /// opposite extreme.
+    InstantDrop,
+    /// Keep a pod around for up to `generations` consecutive updates in which its key is absent,
+    /// in case it reappears (e.g. a filter being toggled on and off), evicting it once it has
+    /// been absent for longer than that.
+    KeepAlive { generations: u32 },
+    /// Never evict. Matches the behaviour before this lifetime policy existed - the default, so
+    /// existing callers keep working unchanged.
+    KeepForever,
+}
+
 /// Content that is derived from data
 ///
 /// This is currently very basic and holds the 'indices' of widgets in a vec,
@@ -174,12 +344,15 @@ type WidgetFromValue<T, K, Aug> = dyn Fn(&T, &Env, K) -> EnsuredPod<T, Aug>;
 ///
 /// Needs to support diffable collections, and range iteration of some kind (for virtualised lists of large objects).
 /// Intersection of these maybe slightly involved.
-///
 pub struct ForEachContent<T, K, Aug: 'static> {
     values_from_data: Box<ValuesFromData<T, K>>,
     make_widget: Box<WidgetFromValue<T, K, Aug>>,
     values: Vec<K>,
     child_widgets: HashMap<K, EnsuredPod<T, Aug>>,
+    last_reconcile: ReconcileResult<K>,
+    last_edit_script: Vec<Edit<K>>,
+    lifetime: ChildLifetime,
+    absent_for: HashMap<K, u32>,
 }
 
 impl<T: Data, K, Aug: Default + Clone + 'static> ForEachContent<T, K, Aug> {
@@ -198,35 +371,155 @@ impl<T: Data, K, Aug: Default + Clone + 'static> ForEachContent<T, K, Aug> {
             }),
             values: Default::default(),
             child_widgets: Default::default(),
+            last_reconcile: ReconcileResult::empty(),
+            last_edit_script: Vec::new(),
+            lifetime: ChildLifetime::KeepForever,
+            absent_for: Default::default(),
         }
     }
+
+    /// Bound how long child widgets are kept alive once their key stops appearing in the
+    /// data-derived value list. Defaults to [`ChildLifetime::KeepForever`].
+    pub fn with_lifetime(mut self, lifetime: ChildLifetime) -> Self {
+        self.lifetime = lifetime;
+        self
+    }
 }
 
 impl<T: Data, K: Hash + Eq + Clone, Aug> ForEachContent<T, K, Aug> {
-    fn update_impl(&mut self, data: &T, env: &Env) -> bool {
-        let mut new_values = (*self.values_from_data)(data, env);
+    /// The keys inserted, removed and moved by the most recently applied update, so a consuming
+    /// layout widget (e.g. something that wants to animate insertions or preserve scroll
+    /// position across a reorder) can react to more than a bare "something changed" bool.
+    pub fn last_reconcile(&self) -> &ReconcileResult<K> {
+        &self.last_reconcile
+    }
+
+    /// The minimal edit script (insert/delete/keep, in new-sequence order) computed by the most
+    /// recent update via [`myers_diff`] - unlike [`ForEachContent::last_reconcile`], this is
+    /// purely positional, so it stays correct even for a value list containing duplicate keys.
+    pub fn last_edit_script(&self) -> &[Edit<K>] {
+        &self.last_edit_script
+    }
+
+    /// Reconcile `self.values`/`self.child_widgets` against a freshly computed key list: existing
+    /// keys keep the `EnsuredPod` (and therefore the widget state) they already have - a pod is
+    /// only ever (re)created for a key that has never been seen before - and moved keys are
+    /// reported by their old/new index rather than being treated as a remove-then-insert. Keys
+    /// that drop out of the value list have their cached pod evicted according to `self.lifetime`
+    /// rather than being kept around forever. The inserted and removed keys are taken from the
+    /// inserts and deletes of a minimal edit script (see [`myers_diff`]), keeping only keys absent
+    /// from the old or the new list respectively, so a key that only moved is reported in `moved`
+    /// alone.
+    ///
+    /// A key that reappears after being evicted (`ChildLifetime::InstantDrop`, or a
+    /// `ChildLifetime::KeepAlive` whose `generations` have elapsed) is indistinguishable from a
+    /// brand new one - its old pod is gone, so `make_widget` is called again - which is the
+    /// intended effect of those policies. Only `ChildLifetime::KeepForever`, the default,
+    /// deliberately keeps serving the same pod (and therefore the same widget state) across a
+    /// disappear/reappear.
+    ///
+    /// `child_widgets` is keyed by `K` alone, so if the value list ever contains the same key
+    /// more than once, every occurrence shares one pod rather than getting an identity of its
+    /// own - there is no secondary, position-based identity to fall back on. That's a caller bug
+    /// (keys are supposed to be unique per update), so it's logged rather than silently
+    /// tolerated.
+    fn update_impl(&mut self, data: &T, env: &Env) -> Vec<Edit<K>> {
+        let new_values = (*self.values_from_data)(data, env);
+
+        let mut seen_values = std::collections::HashSet::with_capacity(new_values.len());
+        if new_values.iter().any(|value| !seen_values.insert(value)) {
+            log::warn!(
+                "ForEachContent's key function produced a duplicate key in the same update; \
+                 every occurrence of a repeated key shares a single child widget instead of \
+                 each getting its own. Make the key function produce unique keys per item."
+            );
+        }
+
+        let old_index: HashMap<K, usize> = self
+            .values
+            .iter()
+            .cloned()
+            .enumerate()
+            .map(|(idx, key)| (key, idx))
+            .collect();
+
+        let edit_script = myers_diff(&self.values, &new_values);
+        let new_value_set: std::collections::HashSet<&K> = new_values.iter().collect();
+        let inserted: Vec<K> = edit_script
+            .iter()
+            .filter_map(|edit| match edit {
+                Edit::Insert(key) if !old_index.contains_key(key) => Some(key.clone()),
+                _ => None,
+            })
+            .collect();
+        let removed: Vec<K> = edit_script
+            .iter()
+            .filter_map(|edit| match edit {
+                Edit::Delete(key) if !new_value_set.contains(key) => Some(key.clone()),
+                _ => None,
+            })
+            .collect();
+        self.last_edit_script = edit_script.clone();
 
         let make_widget = &self.make_widget;
-        for value in &new_values {
+        let mut moved = Vec::new();
+        for (new_idx, value) in new_values.iter().enumerate() {
+            if let Some(&old_idx) = old_index.get(value) {
+                if old_idx != new_idx {
+                    moved.push((old_idx, new_idx));
+                }
+            }
             self.child_widgets
                 .entry(value.clone())
                 .or_insert_with(|| (*make_widget)(data, env, value.clone()));
+            self.absent_for.remove(value);
+        }
+
+        let missing_keys: Vec<K> = self
+            .child_widgets
+            .keys()
+            .filter(|key| !new_value_set.contains(key))
+            .cloned()
+            .collect();
+        let mut to_evict = Vec::new();
+        for key in missing_keys {
+            match self.lifetime {
+                ChildLifetime::InstantDrop => to_evict.push(key),
+                ChildLifetime::KeepForever => {}
+                ChildLifetime::KeepAlive { generations } => {
+                    let count = self.absent_for.entry(key.clone()).or_insert(0);
+                    *count += 1;
+                    if *count > generations {
+                        to_evict.push(key);
+                    }
+                }
+            }
         }
-        std::mem::swap(&mut new_values, &mut self.values);
-        new_values == self.values
+        for key in &to_evict {
+            self.child_widgets.remove(key);
+            self.absent_for.remove(key);
+        }
+
+        self.values = new_values;
+        self.last_reconcile = ReconcileResult {
+            inserted,
+            removed,
+            moved,
+        };
+        edit_script
     }
 }
 
-impl<T: Data, K: Hash + Eq + Clone, Aug> Content<T, Aug> for ForEachContent<T, K, Aug> {
+impl<T: Data, K: Hash + Eq + Clone, Aug> Content<T, Aug, K> for ForEachContent<T, K, Aug> {
     fn content_added(&mut self, data: &T, env: &Env) {
         self.update_impl(data, env);
     }
 
-    fn update(&mut self, old_data: &T, data: &T, env: &Env) -> bool {
+    fn update(&mut self, old_data: &T, data: &T, env: &Env) -> Vec<Edit<K>> {
         if !old_data.same(data) {
             self.update_impl(data, env)
         } else {
-            false
+            Vec::new()
         }
     }
 
@@ -234,7 +527,7 @@ impl<T: Data, K: Hash + Eq + Clone, Aug> Content<T, Aug> for ForEachContent<T, K
         false
     }
 
-    fn child_mut(&mut self, idx: usize) -> Option<&mut EnsuredPod<T, Aug>> {
+    fn child_mut(&mut self, idx: usize, _data: &T, _env: &Env) -> Option<&mut EnsuredPod<T, Aug>> {
         let child_widgets = &mut self.child_widgets;
         if let Some(val) = self.values.get(idx) {
             child_widgets.get_mut(val)
@@ -254,6 +547,160 @@ impl<T: Data, K: Hash + Eq + Clone, Aug> Content<T, Aug> for ForEachContent<T, K
     }
 }
 
+type LenFromData<T> = dyn Fn(&T, &Env) -> usize;
+type WidgetFromIndex<T, K, Aug> = dyn Fn(&T, &Env, usize) -> (K, EnsuredPod<T, Aug>);
+
+/// Content driven by a visible index range rather than materializing every child up front, for
+/// lists too large to give every item a `WidgetPod` (hundreds of thousands of rows). `len()`
+/// still reports the logical total so a scrolling layout widget can reserve space for the whole
+/// list, but only indices inside the window set by [`VirtualContent::set_visible_range`]
+/// (widened by the configured overscan) have a live pod: `child_mut` lazily builds one via
+/// `make_widget` the first time an in-window index is asked for, and out-of-window pods are
+/// evicted according to a [`ChildLifetime`] the next time the window moves.
+pub struct VirtualContent<T, K, Aug: 'static> {
+    len_from_data: Box<LenFromData<T>>,
+    make_widget: Box<WidgetFromIndex<T, K, Aug>>,
+    total_len: usize,
+    visible_range: std::ops::Range<usize>,
+    overscan: usize,
+    lifetime: ChildLifetime,
+    child_widgets: HashMap<usize, (K, EnsuredPod<T, Aug>)>,
+    absent_for: HashMap<usize, u32>,
+}
+
+impl<T: Data, K, Aug: Default + Clone + 'static> VirtualContent<T, K, Aug> {
+    /// Create windowed content for a list of `len_from_data(data, env)` logical items, each
+    /// built (and keyed, for identity across scrolling) by `make_widget` the first time its
+    /// index enters the visible window.
+    pub fn new<W: Widget<T> + 'static>(
+        len_from_data: impl Fn(&T, &Env) -> usize + 'static,
+        make_widget: impl Fn(&T, &Env, usize) -> (K, W) + 'static,
+    ) -> Self {
+        VirtualContent {
+            len_from_data: Box::new(len_from_data),
+            make_widget: Box::new(move |data, env, idx| {
+                let (key, widget) = make_widget(data, env, idx);
+                (key, EnsuredPod::new(widget))
+            }),
+            total_len: 0,
+            visible_range: 0..0,
+            overscan: 0,
+            lifetime: ChildLifetime::KeepForever,
+            child_widgets: Default::default(),
+            absent_for: Default::default(),
+        }
+    }
+
+    /// Indices within `overscan` positions of the visible range are kept materialized too, so
+    /// scrolling a little doesn't immediately pay the cost of building a widget. Defaults to 0.
+    pub fn with_overscan(mut self, overscan: usize) -> Self {
+        self.overscan = overscan;
+        self
+    }
+
+    /// Bound how long an out-of-window pod is kept before being evicted, the same policy
+    /// [`ForEachContent`] uses. Defaults to [`ChildLifetime::KeepForever`].
+    pub fn with_lifetime(mut self, lifetime: ChildLifetime) -> Self {
+        self.lifetime = lifetime;
+        self
+    }
+}
+
+impl<T, K, Aug> VirtualContent<T, K, Aug> {
+    /// Tell this content which logical indices are currently visible (e.g. from a scroll
+    /// viewport). `child_mut` only ever materializes indices inside this range widened by the
+    /// overscan; indices that fall out of it become eligible for eviction.
+    pub fn set_visible_range(&mut self, range: std::ops::Range<usize>) {
+        self.visible_range = range;
+        self.evict_outside_window();
+    }
+
+    fn window(&self) -> std::ops::Range<usize> {
+        let start = self.visible_range.start.saturating_sub(self.overscan);
+        let end = self
+            .visible_range
+            .end
+            .saturating_add(self.overscan)
+            .min(self.total_len);
+        start..end.max(start)
+    }
+}
+
+impl<T, K, Aug> VirtualContent<T, K, Aug> {
+    fn evict_outside_window(&mut self) {
+        let window = self.window();
+        let stale: Vec<usize> = self
+            .child_widgets
+            .keys()
+            .filter(|idx| !window.contains(idx))
+            .cloned()
+            .collect();
+        let mut to_evict = Vec::new();
+        for idx in stale {
+            match self.lifetime {
+                ChildLifetime::InstantDrop => to_evict.push(idx),
+                ChildLifetime::KeepForever => {}
+                ChildLifetime::KeepAlive { generations } => {
+                    let count = self.absent_for.entry(idx).or_insert(0);
+                    *count += 1;
+                    if *count > generations {
+                        to_evict.push(idx);
+                    }
+                }
+            }
+        }
+        for idx in to_evict {
+            self.child_widgets.remove(&idx);
+            self.absent_for.remove(&idx);
+        }
+    }
+}
+
+impl<T: Data, K, Aug> Content<T, Aug, K> for VirtualContent<T, K, Aug> {
+    fn add_child_widget(&mut self, _cw: EnsuredPod<T, Aug>) -> bool {
+        false
+    }
+
+    fn content_added(&mut self, data: &T, env: &Env) {
+        self.total_len = (*self.len_from_data)(data, env);
+    }
+
+    // Unlike `ForEachContent`, there's no key list here to diff: `K` for an out-of-window index
+    // isn't known until `make_widget` materializes it, and doing that for every index just to
+    // report an edit script would defeat the point of windowing. So this always reports an empty
+    // script - real insert/remove/move fidelity across an update is `ForEachContent`'s job.
+    fn update(&mut self, _old_data: &T, data: &T, env: &Env) -> Vec<Edit<K>> {
+        self.total_len = (*self.len_from_data)(data, env);
+        self.evict_outside_window();
+        Vec::new()
+    }
+
+    fn child_mut(&mut self, idx: usize, data: &T, env: &Env) -> Option<&mut EnsuredPod<T, Aug>> {
+        // `window()` is already capped at `total_len`.
+        if !self.window().contains(&idx) {
+            return None;
+        }
+        self.absent_for.remove(&idx);
+        let make_widget = &self.make_widget;
+        let (_key, pod) = self
+            .child_widgets
+            .entry(idx)
+            .or_insert_with(|| (*make_widget)(data, env, idx));
+        Some(pod)
+    }
+
+    fn last_child(&self) -> Option<&EnsuredPod<T, Aug>> {
+        self.total_len
+            .checked_sub(1)
+            .and_then(|idx| self.child_widgets.get(&idx))
+            .map(|(_key, pod)| pod)
+    }
+
+    fn len(&self) -> usize {
+        self.total_len
+    }
+}
+
 pub struct ComposedContent<T, Content1, Content2> {
     phantom_t: PhantomData<T>,
     content1: Content1,
@@ -270,30 +717,51 @@ impl<T, Content1, Content2> ComposedContent<T, Content1, Content2> {
     }
 }
 
-impl<T, Aug, Content1: Content<T, Aug>, Content2: Content<T, Aug>> Content<T, Aug>
-    for ComposedContent<T, Content1, Content2>
+/// An edit-script key from one or the other half of a [`ComposedContent`].
+///
+/// [`ComposedContent`]: struct.ComposedContent.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CombinedKey<K1, K2> {
+    First(K1),
+    Second(K2),
+}
+
+impl<T, Aug, K1, K2, Content1: Content<T, Aug, K1>, Content2: Content<T, Aug, K2>>
+    Content<T, Aug, CombinedKey<K1, K2>> for ComposedContent<T, Content1, Content2>
 {
     fn content_added(&mut self, data: &T, env: &Env) {
         self.content1.content_added(data, env);
         self.content2.content_added(data, env);
     }
 
-    fn update(&mut self, old_data: &T, data: &T, env: &Env) -> bool {
-        let up1 = self.content1.update(old_data, data, env);
-        let up2 = self.content2.update(old_data, data, env);
-        up1 || up2
+    // `content1`'s children always occupy the first `len()` indices, `content2`'s the rest (see
+    // `child_mut` below), so concatenating their edit scripts in that order - tagging each half
+    // with `CombinedKey` to tell them apart - already lines the combined script up with the
+    // combined index space without needing to renumber anything inside `Edit` itself.
+    fn update(&mut self, old_data: &T, data: &T, env: &Env) -> Vec<Edit<CombinedKey<K1, K2>>> {
+        let edits1 = self.content1.update(old_data, data, env);
+        let edits2 = self.content2.update(old_data, data, env);
+        edits1
+            .into_iter()
+            .map(|edit| map_edit(edit, CombinedKey::First))
+            .chain(
+                edits2
+                    .into_iter()
+                    .map(|edit| map_edit(edit, CombinedKey::Second)),
+            )
+            .collect()
     }
 
     fn add_child_widget(&mut self, cw: EnsuredPod<T, Aug>) -> bool {
         self.content2.add_child_widget(cw)
     }
 
-    fn child_mut(&mut self, idx: usize) -> Option<&mut EnsuredPod<T, Aug>> {
+    fn child_mut(&mut self, idx: usize, data: &T, env: &Env) -> Option<&mut EnsuredPod<T, Aug>> {
         let len1 = self.content1.len();
         if idx < len1 {
-            self.content1.child_mut(idx)
+            self.content1.child_mut(idx, data, env)
         } else {
-            self.content2.child_mut(idx - len1)
+            self.content2.child_mut(idx - len1, data, env)
         }
     }
 
@@ -327,24 +795,28 @@ impl<T, C> CondBranch<T, C> {
 }
 
 impl<T, C> CondBranch<T, C> {
-    fn content_added<Aug>(&mut self, data: &T, env: &Env)
+    fn content_added<Aug, K>(&mut self, data: &T, env: &Env)
     where
-        C: Content<T, Aug>,
+        C: Content<T, Aug, K>,
     {
         self.und.content_added(data, env);
         self.shown = true
     }
 
-    fn update<Aug>(&mut self, old_data: &T, data: &T, env: &Env) -> bool
+    // A branch that has just become visible reports no edits even though every one of its
+    // children is new to the screen: `Content::content_added` (unlike `update`) has no return
+    // value to surface them through. `ConditionalContent::update` is the only caller, and treats
+    // an empty script from a freshly-shown branch the same honest way.
+    fn update<Aug, K>(&mut self, old_data: &T, data: &T, env: &Env) -> Vec<Edit<K>>
     where
-        C: Content<T, Aug>,
+        C: Content<T, Aug, K>,
     {
         if self.shown {
             self.und.update(old_data, data, env)
         } else {
             self.und.content_added(data, env);
             self.shown = true;
-            true
+            Vec::new()
         }
     }
 }
@@ -385,8 +857,8 @@ impl<T: Data, ContentTrue, ContentFalse> ConditionalContent<T, ContentTrue, Cont
     }
 }
 
-impl<T: Data, Aug, ContentTrue: Content<T, Aug>, ContentFalse: Content<T, Aug>> Content<T, Aug>
-    for ConditionalContent<T, ContentTrue, ContentFalse>
+impl<T: Data, Aug, KT, KF, ContentTrue: Content<T, Aug, KT>, ContentFalse: Content<T, Aug, KF>>
+    Content<T, Aug, CombinedKey<KT, KF>> for ConditionalContent<T, ContentTrue, ContentFalse>
 {
     fn add_child_widget(&mut self, _cw: EnsuredPod<T, Aug>) -> bool {
         false
@@ -402,35 +874,39 @@ impl<T: Data, Aug, ContentTrue: Content<T, Aug>, ContentFalse: Content<T, Aug>>
         }
     }
 
-    fn update(&mut self, old_data: &T, data: &T, env: &Env) -> bool {
-        let cond_changed = if !old_data.same(data) {
+    // Only one branch is ever shown, so (unlike `ComposedContent`) there's nothing to
+    // concatenate - just the active branch's script, tagged with which branch it came from. See
+    // `CondBranch::update`'s doc comment for why switching branches doesn't itself produce a
+    // full insert/delete script.
+    fn update(&mut self, old_data: &T, data: &T, env: &Env) -> Vec<Edit<CombinedKey<KT, KF>>> {
+        if !old_data.same(data) {
             let new_cond = Some((*self.condition)(data, env));
-            let changed = self.current == new_cond;
             self.current = new_cond;
-            changed
-        } else {
-            false
-        };
-
-        let und_changed = if let Some(cond) = self.current {
-            if cond {
-                self.true_br.update(old_data, data, env)
-            } else {
-                self.false_br.update(old_data, data, env)
-            }
-        } else {
-            false
-        };
+        }
 
-        cond_changed || und_changed
+        match self.current {
+            Some(true) => self
+                .true_br
+                .update(old_data, data, env)
+                .into_iter()
+                .map(|edit| map_edit(edit, CombinedKey::First))
+                .collect(),
+            Some(false) => self
+                .false_br
+                .update(old_data, data, env)
+                .into_iter()
+                .map(|edit| map_edit(edit, CombinedKey::Second))
+                .collect(),
+            None => Vec::new(),
+        }
     }
 
-    fn child_mut(&mut self, idx: usize) -> Option<&mut EnsuredPod<T, Aug>> {
+    fn child_mut(&mut self, idx: usize, data: &T, env: &Env) -> Option<&mut EnsuredPod<T, Aug>> {
         if let Some(cond) = self.current {
             if cond {
-                self.true_br.und.child_mut(idx)
+                self.true_br.und.child_mut(idx, data, env)
             } else {
-                self.false_br.und.child_mut(idx)
+                self.false_br.und.child_mut(idx, data, env)
             }
         } else {
             None
@@ -0,0 +1,26 @@
+use crate::widget::Augmented;
+
+/// Marker augmentation that marks a widget's layout rect as a window drag ("caption") region
+/// when the window uses [`Decorations::Client`], so the platform hit-tests it like an OS
+/// titlebar for dragging, double-click-to-maximize, and snap layouts.
+///
+/// [`Decorations::Client`]: ../enum.Decorations.html#variant.Client
+pub struct CaptionRegion;
+
+/// A widget wrapper whose layout rect is treated as a window drag region under
+/// [`Decorations::Client`]. The platform backend discovers these regions through the widget
+/// tree's [`Widget::augmentation_raw`] mechanism (the same one [`Augmented`] provides), so a
+/// custom unified titlebar only needs to wrap its draggable part in this, rather than
+/// registering drag rects through some separate side channel.
+///
+/// [`Decorations::Client`]: ../enum.Decorations.html#variant.Client
+/// [`Widget::augmentation_raw`]: trait.Widget.html#method.augmentation_raw
+/// [`Augmented`]: struct.Augmented.html
+pub type DraggableArea<W> = Augmented<W, CaptionRegion>;
+
+impl<W> Augmented<W, CaptionRegion> {
+    /// Wrap `widget` so its layout rect acts as a window drag region.
+    pub fn draggable(widget: W) -> Self {
+        Augmented::new(widget, CaptionRegion)
+    }
+}
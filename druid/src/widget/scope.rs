@@ -1,8 +1,16 @@
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::future::Future;
+use std::hash::Hash;
 use std::marker::PhantomData;
+use std::sync::Arc;
+use std::task::{Context as TaskContext, Poll, Wake, Waker};
+use std::thread;
 
 use crate::widget::prelude::*;
 use crate::widget::WidgetWrapper;
-use crate::{Data, Lens, Point, WidgetPod};
+use crate::{Command, Data, ExtEventSink, Lens, Point, Selector, WidgetId, WidgetPod};
 
 /// A policy that controls how a [`Scope`] will interact with its surrounding
 /// application data. Specifically, how to create an initial State from the
@@ -47,6 +55,33 @@ pub trait ScopeTransfer {
 
     /// Update any computed properties that have been invalidated by changes in the state.
     fn update_computed(&self, old_state: &Self::State, state: &mut Self::State, env: &Env) -> bool;
+
+    /// Whether this transfer still needs to be driven by animation frames - for instance to
+    /// poll a background computation for a result. Defaults to `false`.
+    fn wants_anim_frame(&self, _state: &Self::State) -> bool {
+        false
+    }
+
+    /// Called once, when [`LifeCycle::WidgetAdded`] reaches the owning [`Scope`], with a handle
+    /// back into the running application and the `Scope`'s own id. Defaults to a no-op; only a
+    /// transfer that schedules work off the UI thread (like [`ResourceScopeTransfer`]) needs to
+    /// hold on to these to report back later.
+    ///
+    /// [`LifeCycle::WidgetAdded`]: ../enum.LifeCycle.html#variant.WidgetAdded
+    /// [`Scope`]: struct.Scope.html
+    /// [`ResourceScopeTransfer`]: struct.ResourceScopeTransfer.html
+    fn widget_added(&self, _state: &mut Self::State, _handle: ExtEventSink, _widget_id: WidgetId) {}
+
+    /// Handle a [`Command`] addressed to the `Scope` itself, before it would otherwise be
+    /// forwarded to the inner widget. Returns whether the command was consumed. Defaults to
+    /// `false`; only a transfer that submits its own commands (like [`ResourceScopeTransfer`])
+    /// needs this.
+    ///
+    /// [`Command`]: ../struct.Command.html
+    /// [`ResourceScopeTransfer`]: struct.ResourceScopeTransfer.html
+    fn handle_command(&self, _state: &mut Self::State, _cmd: &Command) -> bool {
+        false
+    }
 }
 
 /// A default implementation of [`ScopePolicy`] that takes a function and a transfer.
@@ -186,6 +221,337 @@ impl<L: Lens<State, In>, In: Data, State: Data> ScopeTransfer for LensScopeTrans
     }
 }
 
+/// One declaratively-specified computed field over a [`Scope`]'s `State`: recomputes its
+/// output from its dependency using [`Data::same`] to diff, rather than recomputing (or being
+/// told to recompute) unconditionally on every `update_computed`.
+///
+/// [`Data::same`]: ../trait.Data.html#tymethod.same
+pub struct ComputedFieldSpec<State> {
+    recompute_if_stale: Box<dyn Fn(&mut State) -> bool>,
+}
+
+impl<State: 'static> ComputedFieldSpec<State> {
+    /// Create a computed field that reads its dependency through `dep_lens`, recomputes via
+    /// `compute` only when that dependency has changed since the last call (per [`Data::same`]),
+    /// and writes the result back through `out_lens`.
+    ///
+    /// [`Data::same`]: ../trait.Data.html#tymethod.same
+    pub fn new<Dep: Data, Out: Data>(
+        dep_lens: impl Lens<State, Dep> + 'static,
+        out_lens: impl Lens<State, Out> + 'static,
+        compute: impl Fn(&Dep) -> Out + 'static,
+    ) -> Self {
+        let last_dep: std::cell::RefCell<Option<Dep>> = std::cell::RefCell::new(None);
+        ComputedFieldSpec {
+            recompute_if_stale: Box::new(move |state: &mut State| {
+                let current_dep = dep_lens.with(state, |dep| dep.clone());
+                let is_stale = last_dep
+                    .borrow()
+                    .as_ref()
+                    .map_or(true, |last| !last.same(&current_dep));
+                if is_stale {
+                    let new_out = compute(&current_dep);
+                    out_lens.with_mut(state, |out| *out = new_out);
+                    *last_dep.borrow_mut() = Some(current_dep);
+                }
+                is_stale
+            }),
+        }
+    }
+}
+
+/// A [`ScopeTransfer`] that wraps another transfer, running a set of [`ComputedFieldSpec`]s
+/// after its `update_computed` so memoized fields declared up front stay in sync with their
+/// dependencies, instead of being recomputed by hand inside a custom transfer impl.
+pub struct MemoizedScopeTransfer<Inner: ScopeTransfer> {
+    inner: Inner,
+    fields: Vec<ComputedFieldSpec<Inner::State>>,
+}
+
+impl<Inner: ScopeTransfer> MemoizedScopeTransfer<Inner> {
+    /// Wrap `inner`, adding the memoized computed fields in `fields`.
+    pub fn new(inner: Inner, fields: Vec<ComputedFieldSpec<Inner::State>>) -> Self {
+        MemoizedScopeTransfer { inner, fields }
+    }
+}
+
+impl<Inner: ScopeTransfer> ScopeTransfer for MemoizedScopeTransfer<Inner> {
+    type In = Inner::In;
+    type State = Inner::State;
+
+    fn read_input(&self, state: &mut Self::State, input: &Self::In, env: &Env) {
+        self.inner.read_input(state, input, env);
+    }
+
+    fn write_back_input(&self, state: &Self::State, input: &mut Self::In) {
+        self.inner.write_back_input(state, input);
+    }
+
+    fn update_computed(&self, old_state: &Self::State, state: &mut Self::State, env: &Env) -> bool {
+        let inner_changed = self.inner.update_computed(old_state, state, env);
+        let mut fields_changed = false;
+        for field in &self.fields {
+            fields_changed |= (field.recompute_if_stale)(state);
+        }
+        inner_changed || fields_changed
+    }
+
+    fn wants_anim_frame(&self, state: &Self::State) -> bool {
+        self.inner.wants_anim_frame(state)
+    }
+}
+
+/// The result of fetching a [`ResourceScopePolicy`]'s resource, for use as part of a [`Scope`]'s
+/// state.
+///
+/// [`ResourceScopePolicy`]: struct.ResourceScopePolicy.html
+/// [`Scope`]: struct.Scope.html
+#[derive(Clone, Debug)]
+pub enum Resource<T, E> {
+    /// The fetch for the current input is still running.
+    Pending,
+    /// The fetch for the current input completed with this value.
+    Ready(T),
+    /// The fetch for the current input failed with this error.
+    Failed(E),
+}
+
+impl<T: Data, E: Data> Data for Resource<T, E> {
+    fn same(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Resource::Pending, Resource::Pending) => true,
+            (Resource::Ready(a), Resource::Ready(b)) => a.same(b),
+            (Resource::Failed(a), Resource::Failed(b)) => a.same(b),
+            _ => false,
+        }
+    }
+}
+
+/// The state a [`Scope`] built with [`Scope::from_resource`] maintains: the input the resource
+/// was last fetched for, and the fetch's current outcome.
+///
+/// [`Scope`]: struct.Scope.html
+/// [`Scope::from_resource`]: struct.Scope.html#method.from_resource
+#[derive(Clone, Debug)]
+pub struct ResourceState<In, T, E> {
+    /// The input the current (or most recently started) fetch was run against.
+    pub input: In,
+    /// The fetch's current outcome. The inner widget renders this.
+    pub resource: Resource<T, E>,
+}
+
+impl<In: Data, T: Data, E: Data> Data for ResourceState<In, T, E> {
+    fn same(&self, other: &Self) -> bool {
+        self.input.same(&other.input) && self.resource.same(&other.resource)
+    }
+}
+
+/// A future's single poll, blocking the calling thread between polls rather than registering
+/// with a reactor - there's no I/O driver in this crate to register with. Adequate for a fetch
+/// future that just awaits e.g. an HTTP response on its own connection; it is not a general
+/// purpose executor.
+struct ParkWaker(thread::Thread);
+
+impl Wake for ParkWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+fn block_on<F: Future>(fut: F) -> F::Output {
+    let mut fut = Box::pin(fut);
+    let waker = Waker::from(Arc::new(ParkWaker(thread::current())));
+    let mut cx = TaskContext::from_waker(&waker);
+    loop {
+        match fut.as_mut().poll(&mut cx) {
+            Poll::Ready(value) => return value,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+/// Selector a [`ResourceScopeTransfer`] submits to its own [`Scope`] widget, from the background
+/// thread driving the fetch, once that fetch completes. The payload is a boxed
+/// `(u64, Result<T, E>)` - the generation the fetch was spawned for, and its outcome - type-erased
+/// because a `Selector` is a monomorphic type but many different `ResourceScopePolicy<In, T, E,
+/// ..>` instantiations share this one key, the same way [`SUB_WINDOW_HOST_TO_PARENT`] is shared
+/// across `SubWindowHost`'s data types.
+///
+/// [`ResourceScopeTransfer`]: struct.ResourceScopeTransfer.html
+/// [`Scope`]: struct.Scope.html
+/// [`SUB_WINDOW_HOST_TO_PARENT`]: ../commands/constant.SUB_WINDOW_HOST_TO_PARENT.html
+const RESOURCE_READY: Selector<Box<dyn Any + Send>> =
+    Selector::new("druid-builtin.scope.resource-ready");
+
+/// A [`ScopeTransfer`] that fetches a resource in the background and reports it back to its
+/// `Scope`; it is created by [`ResourceScopePolicy`]. See that policy for the mechanism.
+///
+/// [`ScopeTransfer`]: trait.ScopeTransfer.html
+/// [`ResourceScopePolicy`]: struct.ResourceScopePolicy.html
+pub struct ResourceScopeTransfer<In, T, E, Fut, F> {
+    fetch: F,
+    // Not part of `State`/`Data` - a handle back into the running application and the generation
+    // counter are bookkeeping for the fetch mechanism itself, not reactive state to render.
+    handle: RefCell<Option<ExtEventSink>>,
+    widget_id: Cell<Option<WidgetId>>,
+    generation: Cell<u64>,
+    phantom: PhantomData<(In, T, E, Fut)>,
+}
+
+impl<In, T, E, Fut, F> ResourceScopeTransfer<In, T, E, Fut, F>
+where
+    In: Data,
+    T: Data + Send + 'static,
+    E: Data + Send + 'static,
+    Fut: Future<Output = Result<T, E>> + Send + 'static,
+    F: Fn(&In) -> Fut,
+{
+    /// Bump the generation and, if we already have a handle back into the app (i.e.
+    /// `widget_added` has run), spawn a fetch for `input` reporting back under the new
+    /// generation. Before `widget_added` runs there's nowhere to report to yet; `widget_added`
+    /// spawns the initial fetch itself once it has a handle.
+    fn spawn_fetch(&self, input: &In) {
+        let generation = self.generation.get().wrapping_add(1);
+        self.generation.set(generation);
+        let (handle, widget_id) = match (self.handle.borrow().clone(), self.widget_id.get()) {
+            (Some(handle), Some(widget_id)) => (handle, widget_id),
+            _ => return,
+        };
+        let fut = (self.fetch)(input);
+        thread::spawn(move || {
+            let result = block_on(fut);
+            let payload: Box<dyn Any + Send> = Box::new((generation, result));
+            let _ = handle.submit_command(RESOURCE_READY, payload, widget_id);
+        });
+    }
+}
+
+impl<In, T, E, Fut, F> ScopeTransfer for ResourceScopeTransfer<In, T, E, Fut, F>
+where
+    In: Data,
+    T: Data + Send + 'static,
+    E: Data + Send + 'static,
+    Fut: Future<Output = Result<T, E>> + Send + 'static,
+    F: Fn(&In) -> Fut,
+{
+    type In = In;
+    type State = ResourceState<In, T, E>;
+
+    fn read_input(&self, state: &mut Self::State, input: &Self::In, _env: &Env) {
+        if !state.input.same(input) {
+            state.input = input.clone();
+            state.resource = Resource::Pending;
+            self.spawn_fetch(input);
+        }
+    }
+
+    fn write_back_input(&self, _state: &Self::State, _input: &mut Self::In) {}
+
+    fn update_computed(
+        &self,
+        _old_state: &Self::State,
+        _state: &mut Self::State,
+        _env: &Env,
+    ) -> bool {
+        false
+    }
+
+    fn widget_added(&self, state: &mut Self::State, handle: ExtEventSink, widget_id: WidgetId) {
+        *self.handle.borrow_mut() = Some(handle);
+        self.widget_id.set(Some(widget_id));
+        self.spawn_fetch(&state.input);
+    }
+
+    fn handle_command(&self, state: &mut Self::State, cmd: &Command) -> bool {
+        if !cmd.is(RESOURCE_READY) {
+            return false;
+        }
+        match cmd
+            .get_unchecked(RESOURCE_READY)
+            .downcast_ref::<(u64, Result<T, E>)>()
+        {
+            Some((generation, result)) if *generation == self.generation.get() => {
+                state.resource = match result {
+                    Ok(value) => Resource::Ready(value.clone()),
+                    Err(error) => Resource::Failed(error.clone()),
+                };
+            }
+            // A result for an input that's since been superseded by a newer one - the staleness
+            // case the generation counter exists to catch. Discard it.
+            Some(_) => {}
+            None => log::warn!(
+                "ResourceScopeTransfer received a resource-ready command that didn't downcast \
+                 to its expected result type; ignoring it."
+            ),
+        }
+        true
+    }
+}
+
+/// A [`ScopePolicy`] that turns an async fetcher into reactive [`Scope`] state: `fetch` is run
+/// against the input on a background thread, its `Future` driven to completion there, and the
+/// result reported back as [`Resource::Ready`]/[`Resource::Failed`] through the
+/// [`ExtEventSink`] captured at [`LifeCycle::WidgetAdded`]. Whenever `read_input` sees an input
+/// that is `!same()` as the one already cached, `fetch` is run again and the state drops back to
+/// [`Resource::Pending`].
+///
+/// Each fetch is tagged with a monotonically increasing generation id; a result is only written
+/// into state if its generation still matches the most recently spawned fetch, so a result for an
+/// input that's since been superseded is silently discarded rather than clobbering a newer
+/// result or reviving a stale one.
+///
+/// [`Scope`]: struct.Scope.html
+/// [`ExtEventSink`]: ../struct.ExtEventSink.html
+/// [`LifeCycle::WidgetAdded`]: ../enum.LifeCycle.html#variant.WidgetAdded
+pub struct ResourceScopePolicy<In, T, E, Fut, F> {
+    fetch: F,
+    phantom: PhantomData<(In, T, E, Fut)>,
+}
+
+impl<In, T, E, Fut, F> ResourceScopePolicy<In, T, E, Fut, F>
+where
+    F: Fn(&In) -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+{
+    /// Create a policy that fetches the scope's state by calling `fetch` with the input,
+    /// re-running it whenever the input changes.
+    pub fn new(fetch: F) -> Self {
+        ResourceScopePolicy {
+            fetch,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<In, T, E, Fut, F> ScopePolicy for ResourceScopePolicy<In, T, E, Fut, F>
+where
+    In: Data,
+    T: Data + Send + 'static,
+    E: Data + Send + 'static,
+    Fut: Future<Output = Result<T, E>> + Send + 'static,
+    F: Fn(&In) -> Fut + 'static,
+{
+    type In = In;
+    type State = ResourceState<In, T, E>;
+    type Transfer = ResourceScopeTransfer<In, T, E, Fut, F>;
+
+    fn create(self, inner: &Self::In, _env: &Env) -> (Self::State, Self::Transfer) {
+        (
+            ResourceState {
+                input: inner.clone(),
+                resource: Resource::Pending,
+            },
+            ResourceScopeTransfer {
+                fetch: self.fetch,
+                handle: RefCell::new(None),
+                widget_id: Cell::new(None),
+                generation: Cell::new(0),
+                phantom: PhantomData,
+            },
+        )
+    }
+}
+
 enum ScopeContent<SP: ScopePolicy> {
     Policy {
         policy: Option<SP>,
@@ -344,19 +710,51 @@ impl<SP: ScopePolicy, W: Widget<SP::State>> Scope<SP, W> {
         }
     }
 
-    fn update_computed_and_write_back(&mut self, data: &mut SP::In, _env: &Env) -> bool {
+    /// Updates computed properties and writes state changes back to `data`, returning whether
+    /// `data` actually ended up different - either because a computed cell recomputed, or
+    /// because the written-back input differs from what was there before (per [`Data::same`]).
+    /// Callers use this to skip requesting an update when nothing actually changed, rather than
+    /// unconditionally forcing one on every event.
+    ///
+    /// [`Data::same`]: ../trait.Data.html#tymethod.same
+    fn update_computed_and_write_back(&mut self, data: &mut SP::In, env: &Env) -> bool {
         let inner = &mut self.inner;
 
         if let ScopeContent::Transfer { state, transfer } = &mut self.content {
             if let Some(old_state) = &inner.old_data {
-                if !old_state.same(state) {
-                    //transfer.update_computed(old_state, state, env);
+                let computed_changed = transfer.update_computed(old_state, state, env);
+                if computed_changed || !old_state.same(state) {
+                    let old_data = data.clone();
                     transfer.write_back_input(state, data);
-                    return true;
+                    return computed_changed || !old_data.same(data);
                 }
             }
         }
-        true
+        false
+    }
+
+    /// Apply `f` to the scope's internal state, then perform a single coalesced write-back and
+    /// (conditional) update request, rather than each mutation inside `f` separately triggering
+    /// its own synchronization round-trip. Borrowed from the `batch()` idea in signal-based
+    /// reactive frameworks; useful for bulk programmatic edits to the scope's state.
+    ///
+    /// Has no effect if called before the scope has produced its state (i.e. before
+    /// [`LifeCycle::WidgetAdded`] has reached it).
+    ///
+    /// [`LifeCycle::WidgetAdded`]: ../enum.LifeCycle.html#variant.WidgetAdded
+    pub fn batch(
+        &mut self,
+        ctx: &mut EventCtx,
+        data: &mut SP::In,
+        env: &Env,
+        f: impl FnOnce(&mut SP::State),
+    ) {
+        if let Some(state) = self.state_mut() {
+            f(state);
+        }
+        if self.update_computed_and_write_back(data, env) {
+            ctx.request_update();
+        }
     }
 }
 
@@ -388,20 +786,74 @@ impl<In: Data, State: Data, W: Widget<State>> Scope<IsolatedScopePolicy<In, Stat
     }
 }
 
+impl<
+        In: Data,
+        T: Data + Send + 'static,
+        E: Data + Send + 'static,
+        Fut: Future<Output = Result<T, E>> + Send + 'static,
+        F: Fn(&In) -> Fut + 'static,
+        W: Widget<ResourceState<In, T, E>>,
+    > Scope<ResourceScopePolicy<In, T, E, Fut, F>, W>
+{
+    /// Create a scope whose state is fetched asynchronously from the input via `fetch`,
+    /// re-running the fetch whenever the input changes. See [`ResourceScopePolicy`] for the
+    /// mechanism; the inner widget renders `state.resource`.
+    ///
+    /// [`ResourceScopePolicy`]: struct.ResourceScopePolicy.html
+    pub fn from_resource(fetch: F, inner: W) -> Self {
+        Scope::new(ResourceScopePolicy::new(fetch), inner)
+    }
+}
+
 impl<SP: ScopePolicy, W: Widget<SP::State>> Widget<SP::In> for Scope<SP, W> {
     fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut SP::In, env: &Env) {
+        if let Event::Command(cmd) = event {
+            if let ScopeContent::Transfer { state, transfer } = &mut self.content {
+                if transfer.handle_command(state, cmd) {
+                    ctx.set_handled();
+                    self.update_computed_and_write_back(data, env);
+                    // The command can change the state without touching the input (e.g. a
+                    // resource result arriving), so the inner widget needs an update pass either way.
+                    ctx.request_update();
+                    return;
+                }
+            }
+        }
+
         self.with_state_mut(data, env, |state, inner| {
             inner.event(ctx, event, state, env);
         });
 
-        self.update_computed_and_write_back(data, env);
-        ctx.request_update()
+        if self.update_computed_and_write_back(data, env) {
+            ctx.request_update();
+        }
     }
 
     fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &SP::In, env: &Env) {
         self.with_state(false, data, env, |state, inner| {
             inner.lifecycle(ctx, event, state, env)
         });
+
+        if let ScopeContent::Transfer { state, transfer } = &mut self.content {
+            match event {
+                LifeCycle::WidgetAdded => {
+                    transfer.widget_added(state, ctx.get_external_handle(), ctx.widget_id());
+                    if transfer.wants_anim_frame(state) {
+                        ctx.request_anim_frame();
+                    }
+                }
+                LifeCycle::AnimFrame(_) => {
+                    let old_state = state.clone();
+                    if transfer.update_computed(&old_state, state, env) {
+                        ctx.request_update();
+                    }
+                    if transfer.wants_anim_frame(state) {
+                        ctx.request_anim_frame();
+                    }
+                }
+                _ => {}
+            }
+        }
     }
 
     fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &SP::In, data: &SP::In, env: &Env) {
@@ -434,3 +886,121 @@ impl<SP: ScopePolicy, W: Widget<SP::State>> Widget<SP::In> for Scope<SP, W> {
 impl<SP: ScopePolicy, W: Widget<SP::State>> WidgetWrapper for Scope<SP, W> {
     widget_wrapper_pod_body!(W, inner);
 }
+
+/// Diffs `new_keys` against the keys already present in `contents`, reusing the entry for any
+/// key that still appears, dropping entries whose key has disappeared, and creating new ones via
+/// `f`. This is the same keyed-reconciliation idea as `ensure_for_tabs` in the tabs widgets,
+/// generalized from `TabsFromData::TabKey` to a plain `K: Eq + Hash`.
+fn reconcile_keyed<K: Eq + Hash + Clone, Content>(
+    contents: &mut Vec<(K, Content)>,
+    new_keys: &[K],
+    f: impl Fn(&K) -> Content,
+) {
+    let mut existing_by_key: HashMap<K, Content> = contents.drain(..).collect();
+    for key in new_keys {
+        let next = existing_by_key.remove(key).unwrap_or_else(|| f(key));
+        contents.push((key.clone(), next));
+    }
+}
+
+/// A widget that renders a `Vec<Item>` as child widgets with stable identity across updates,
+/// reconciling the child list against `key_fn` rather than rebuilding every child whenever the
+/// `Vec`'s length or order changes.
+///
+/// Children whose key is still present are reused in place, just fed the new item data; children
+/// for new keys are created via `make_widget`; children for keys that have disappeared are
+/// dropped. The retained key→pod list is exactly the kind of private, non-app-visible bookkeeping
+/// [`Scope`] exists to encapsulate, so `KeyedList` keeps it as plain internal widget state rather
+/// than exposing it to the surrounding application.
+///
+/// [`Scope`]: struct.Scope.html
+pub struct KeyedList<Item, K: Eq + Hash + Clone, W: Widget<Item>> {
+    key_fn: Box<dyn Fn(&Item) -> K>,
+    make_widget: Box<dyn Fn() -> W>,
+    children: Vec<(K, WidgetPod<Item, W>)>,
+}
+
+impl<Item, K: Eq + Hash + Clone, W: Widget<Item>> KeyedList<Item, K, W> {
+    /// Create a `KeyedList` that derives each item's identity via `key_fn` and builds new child
+    /// widgets via `make_widget`.
+    pub fn new(
+        key_fn: impl Fn(&Item) -> K + 'static,
+        make_widget: impl Fn() -> W + 'static,
+    ) -> Self {
+        KeyedList {
+            key_fn: Box::new(key_fn),
+            make_widget: Box::new(make_widget),
+            children: Vec::new(),
+        }
+    }
+
+    fn reconcile(&mut self, items: &[Item]) {
+        let keys: Vec<K> = items.iter().map(|item| (self.key_fn)(item)).collect();
+        let make_widget = &self.make_widget;
+        reconcile_keyed(&mut self.children, &keys, |_key| {
+            WidgetPod::new(make_widget())
+        });
+    }
+}
+
+impl<Item: Data, K: Eq + Hash + Clone, W: Widget<Item>> Widget<Vec<Item>>
+    for KeyedList<Item, K, W>
+{
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut Vec<Item>, env: &Env) {
+        for ((_, child), item) in self.children.iter_mut().zip(data.iter_mut()) {
+            child.event(ctx, event, item, env);
+        }
+    }
+
+    fn lifecycle(
+        &mut self,
+        ctx: &mut LifeCycleCtx,
+        event: &LifeCycle,
+        data: &Vec<Item>,
+        env: &Env,
+    ) {
+        if let LifeCycle::WidgetAdded = event {
+            self.reconcile(data);
+            ctx.children_changed();
+        }
+        for ((_, child), item) in self.children.iter_mut().zip(data.iter()) {
+            child.lifecycle(ctx, event, item, env);
+        }
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &Vec<Item>, data: &Vec<Item>, env: &Env) {
+        let old_keys: Vec<K> = self.children.iter().map(|(key, _)| key.clone()).collect();
+        self.reconcile(data);
+        let new_keys: Vec<K> = self.children.iter().map(|(key, _)| key.clone()).collect();
+        if old_keys != new_keys {
+            ctx.children_changed();
+        }
+        for ((_, child), item) in self.children.iter_mut().zip(data.iter()) {
+            child.update(ctx, item, env);
+        }
+    }
+
+    fn layout(
+        &mut self,
+        ctx: &mut LayoutCtx,
+        bc: &BoxConstraints,
+        data: &Vec<Item>,
+        env: &Env,
+    ) -> Size {
+        let mut y = 0.0;
+        let mut width = 0.0_f64;
+        for ((_, child), item) in self.children.iter_mut().zip(data.iter()) {
+            let size = child.layout(ctx, bc, item, env);
+            child.set_origin(ctx, item, env, Point::new(0.0, y));
+            y += size.height;
+            width = width.max(size.width);
+        }
+        bc.constrain(Size::new(width, y))
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &Vec<Item>, env: &Env) {
+        for ((_, child), item) in self.children.iter_mut().zip(data.iter()) {
+            child.paint(ctx, item, env);
+        }
+    }
+}
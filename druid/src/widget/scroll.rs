@@ -17,9 +17,13 @@
 use std::f64::INFINITY;
 
 use crate::widget::prelude::*;
-use crate::widget::{Axis, BindableProperty, Bindable, ClipBox};
-use crate::{scroll_component::*, Data, Rect, Vec2, WidgetPod};
+use crate::widget::{
+    AccessCtx, AccessRole, AfterLayoutCtx, Axis, Bindable, BindableProperty, ClipBox,
+};
+use crate::{scroll_component::*, theme, Data, Point, Rect, Vec2, WidgetId, WidgetPod};
+use std::cell::RefCell;
 use std::marker::PhantomData;
+use std::rc::Rc;
 
 #[derive(Debug, Clone)]
 enum ScrollDirection {
@@ -28,6 +32,243 @@ enum ScrollDirection {
     Horizontal,
 }
 
+/// How a [`Scroll`]'s scrollbar behaves on one axis, modeled on GTK's `ScrolledWindow` policy.
+///
+/// [`Scroll`]: struct.Scroll.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScrollbarPolicy {
+    /// Always reserve gutter space on this axis and draw the bar, even if the content fits.
+    Always,
+    /// Draw the bar only when the content overflows the viewport on this axis. The default.
+    Automatic,
+    /// Allow scrolling by wheel/drag on this axis, but never draw a bar or reserve gutter space.
+    Never,
+    /// Don't scroll on this axis at all; the offset is only exposed through [`ScrollToProperty`]
+    /// for a scrollbar placed elsewhere in the widget tree.
+    ///
+    /// [`ScrollToProperty`]: struct.ScrollToProperty.html
+    External,
+}
+
+/// Friction applied to kinetic scroll velocity per 16ms of animation: a flick keeps about a fifth
+/// of its speed after half a second, and coasting ends once the speed drops below
+/// `KINETIC_EPSILON_SQUARED` rather than going on forever.
+const KINETIC_FRICTION: f64 = 0.95;
+/// Below this squared velocity (in px per 16ms, squared) kinetic scrolling stops rather than
+/// creeping along imperceptibly forever.
+const KINETIC_EPSILON_SQUARED: f64 = 0.01;
+
+/// A shareable scroll-position model, modeled on GTK's `Adjustment`: `value` is the current
+/// offset on one axis, `lower`/`upper` bound the scrollable range, and `page_size` is the extent
+/// of the viewport. Wrap one in `Rc<RefCell<_>>` and pass it to [`Scroll::with_external_adjustment`]
+/// on two or more widgets to keep their positions on that axis synchronized - e.g. a frozen
+/// header that pans horizontally with a table body, or two side-by-side diff panes.
+///
+/// [`Scroll::with_external_adjustment`]: struct.Scroll.html#method.with_external_adjustment
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Adjustment {
+    pub value: f64,
+    pub lower: f64,
+    pub upper: f64,
+    pub page_size: f64,
+    changed: bool,
+}
+
+impl Adjustment {
+    pub fn new(lower: f64, upper: f64, page_size: f64) -> Self {
+        Adjustment {
+            value: lower,
+            lower,
+            upper,
+            page_size,
+            changed: false,
+        }
+    }
+
+    fn set_value(&mut self, value: f64) {
+        let max_value = (self.upper - self.page_size).max(self.lower);
+        let clamped = value.max(self.lower).min(max_value);
+        if clamped != self.value {
+            self.value = clamped;
+            self.changed = true;
+        }
+    }
+
+    fn set_extent(&mut self, upper: f64, page_size: f64) {
+        if self.upper != upper || self.page_size != page_size {
+            self.upper = upper;
+            self.page_size = page_size;
+            self.changed = true;
+        }
+    }
+
+    /// Returns whether the adjustment has changed since the last call, clearing the flag.
+    fn take_changed(&mut self) -> bool {
+        std::mem::replace(&mut self.changed, false)
+    }
+}
+
+/// A pending, not-yet-applied request made through a [`ScrollController`].
+///
+/// [`ScrollController`]: struct.ScrollController.html
+#[derive(Debug, Clone)]
+enum ScrollRequest {
+    To(Point),
+    By(Vec2),
+    ToVisible(Rect),
+    EnsureChildVisible(WidgetId),
+}
+
+struct ScrollControllerState {
+    pending: Vec<ScrollRequest>,
+    offset: Vec2,
+    /// Which axis (or axes, if `None`) every `Scroll` sharing this controller re-applies the
+    /// shared offset on - see [`ScrollController::linked`].
+    ///
+    /// [`ScrollController::linked`]: struct.ScrollController.html#method.linked
+    axes: Option<Axis>,
+}
+
+/// A handle for driving a [`Scroll`] programmatically and for keeping two or more `Scroll`s in
+/// lockstep, generalizing the hand-rolled `BindingScrollOffsets` pattern (syncing scroll
+/// offsets through a `Vec2` in app data via a [`Binding`]) into a reusable, data-free API.
+///
+/// Attach one to a `Scroll` with [`Scroll::controller`]. `scroll_to`, `scroll_by` and
+/// `scroll_to_visible` queue a request that is applied (and cleared) the next time that
+/// `Scroll` processes an event, the same way [`Scroll::with_external_adjustment`] defers writing
+/// a shared [`Adjustment`] until its widget's next pass rather than reaching into layout state
+/// synchronously.
+///
+/// There's no separate `group_id` to mint: a `ScrollController` is already shareable (it's a
+/// cheap `Rc` handle, `Clone` is shallow), so "linking" two or more `Scroll`s is just attaching
+/// the *same* controller - built with [`linked`] rather than [`new`] - to each of them with
+/// `.controller(handle.clone())`. Every `Scroll` sharing a controller re-applies whatever offset
+/// any member last reported (on the axes [`linked`] was given) the next time it processes an
+/// event, and requests a repaint if that actually moved it - so the group stays in lockstep
+/// without the app having to round-trip offsets through its own data.
+///
+/// [`Scroll`]: struct.Scroll.html
+/// [`Scroll::controller`]: struct.Scroll.html#method.controller
+/// [`Scroll::with_external_adjustment`]: struct.Scroll.html#method.with_external_adjustment
+/// [`Adjustment`]: struct.Adjustment.html
+/// [`Binding`]: trait.Binding.html
+/// [`ScrollController::offset`]: struct.ScrollController.html#method.offset
+/// [`linked`]: struct.ScrollController.html#method.linked
+/// [`new`]: struct.ScrollController.html#method.new
+#[derive(Clone)]
+pub struct ScrollController {
+    inner: Rc<RefCell<ScrollControllerState>>,
+}
+
+impl Default for ScrollController {
+    fn default() -> Self {
+        ScrollController {
+            inner: Rc::new(RefCell::new(ScrollControllerState {
+                pending: Vec::new(),
+                offset: Vec2::ZERO,
+                axes: None,
+            })),
+        }
+    }
+}
+
+impl ScrollController {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a controller for a linked group of `Scroll`s, kept synchronized on `axes` (`None`
+    /// meaning both): attach the same `ScrollController` (via `.clone()`) to each member with
+    /// [`Scroll::controller`], and whichever one last moved will carry the others along on their
+    /// next event.
+    ///
+    /// [`Scroll::controller`]: struct.Scroll.html#method.controller
+    pub fn linked(axes: Option<Axis>) -> Self {
+        ScrollController {
+            inner: Rc::new(RefCell::new(ScrollControllerState {
+                pending: Vec::new(),
+                offset: Vec2::ZERO,
+                axes,
+            })),
+        }
+    }
+
+    /// The offset a `Scroll` sharing this controller should adopt, given its own `current`
+    /// offset: `shared` on whichever axes [`linked`] selected, `current` unchanged on the rest.
+    ///
+    /// [`linked`]: struct.ScrollController.html#method.linked
+    fn synced_offset(&self, current: Vec2) -> Vec2 {
+        let shared = self.offset();
+        match self.inner.borrow().axes {
+            Some(Axis::Horizontal) => Vec2::new(shared.x, current.y),
+            Some(Axis::Vertical) => Vec2::new(current.x, shared.y),
+            None => shared,
+        }
+    }
+
+    /// Scroll so the viewport's origin lands exactly on `point`, clamped to the content the
+    /// attached `Scroll`(s) actually have once applied.
+    pub fn scroll_to(&self, point: Point) {
+        self.inner
+            .borrow_mut()
+            .pending
+            .push(ScrollRequest::To(point));
+    }
+
+    /// Scroll by `delta` units, the same as [`Scroll::scroll_by`] but without holding a
+    /// reference to the `Scroll` itself.
+    ///
+    /// [`Scroll::scroll_by`]: struct.Scroll.html#method.scroll_by
+    pub fn scroll_by(&self, delta: Vec2) {
+        self.inner
+            .borrow_mut()
+            .pending
+            .push(ScrollRequest::By(delta));
+    }
+
+    /// Scroll the minimal distance needed to bring `region` into view, the same as
+    /// [`Scroll::scroll_to`].
+    ///
+    /// [`Scroll::scroll_to`]: struct.Scroll.html#method.scroll_to
+    pub fn scroll_to_visible(&self, region: Rect) {
+        self.inner
+            .borrow_mut()
+            .pending
+            .push(ScrollRequest::ToVisible(region));
+    }
+
+    /// Scroll the minimal distance needed to bring the descendant `child` into view.
+    ///
+    /// This can only be resolved once the widget tree exposes a way to look up an arbitrary
+    /// descendant's layout rect by [`WidgetId`] - `Scroll` wraps a single opaque child widget and
+    /// has no such lookup in this snapshot (the after-layout hitbox pass added alongside
+    /// [`AfterLayoutCtx`] would be the natural source for it). Until that plumbing exists, the
+    /// request is recorded and logged rather than silently dropped.
+    ///
+    /// [`WidgetId`]: struct.WidgetId.html
+    /// [`AfterLayoutCtx`]: struct.AfterLayoutCtx.html
+    pub fn ensure_child_visible(&self, child: WidgetId) {
+        self.inner
+            .borrow_mut()
+            .pending
+            .push(ScrollRequest::EnsureChildVisible(child));
+    }
+
+    /// The scroll offset most recently reported by any `Scroll` this controller is attached to -
+    /// how a linked group observes "where did the other scroll end up".
+    pub fn offset(&self) -> Vec2 {
+        self.inner.borrow().offset
+    }
+
+    fn take_pending(&self) -> Vec<ScrollRequest> {
+        std::mem::take(&mut self.inner.borrow_mut().pending)
+    }
+
+    fn set_offset(&self, offset: Vec2) {
+        self.inner.borrow_mut().offset = offset;
+    }
+}
+
 /// A container that scrolls its contents.
 ///
 /// This container holds a single child, and uses the wheel to scroll it
@@ -44,6 +285,16 @@ pub struct Scroll<T, W> {
     clip: ClipBox<T, W>,
     scroll_component: ScrollComponent,
     direction: ScrollDirection,
+    h_policy: ScrollbarPolicy,
+    v_policy: ScrollbarPolicy,
+    external_adjustment: Option<(Axis, Rc<RefCell<Adjustment>>)>,
+    controller: Option<ScrollController>,
+    // The offset last reconciled with `controller`, either ours (just reported) or a linked
+    // peer's (just adopted) - lets `event` tell "the shared offset is stale, still ours" apart
+    // from "a peer moved it, follow" without the two cases fighting each other every pass.
+    last_synced_offset: Option<Vec2>,
+    kinetic: bool,
+    velocity: Vec2,
 }
 
 impl<T, W: Widget<T>> Scroll<T, W> {
@@ -57,13 +308,21 @@ impl<T, W: Widget<T>> Scroll<T, W> {
             clip: ClipBox::new(child),
             scroll_component: ScrollComponent::new(),
             direction: ScrollDirection::Bidirectional,
+            h_policy: ScrollbarPolicy::Automatic,
+            v_policy: ScrollbarPolicy::Automatic,
+            external_adjustment: None,
+            controller: None,
+            last_synced_offset: None,
+            kinetic: false,
+            velocity: Vec2::ZERO,
         }
     }
 
     /// Restrict scrolling to the vertical axis while locking child width.
     pub fn vertical(mut self) -> Self {
         self.direction = ScrollDirection::Vertical;
-        self.scroll_component.scrollbars_enabled = ScrollbarsEnabled::Vertical;
+        self.h_policy = ScrollbarPolicy::Never;
+        self.v_policy = ScrollbarPolicy::Automatic;
         self.clip.set_constrain_vertical(false);
         self.clip.set_constrain_horizontal(true);
         self
@@ -72,27 +331,74 @@ impl<T, W: Widget<T>> Scroll<T, W> {
     /// Restrict scrolling to the horizontal axis while locking child height.
     pub fn horizontal(mut self) -> Self {
         self.direction = ScrollDirection::Horizontal;
-        self.scroll_component.scrollbars_enabled = ScrollbarsEnabled::Horizontal;
+        self.h_policy = ScrollbarPolicy::Automatic;
+        self.v_policy = ScrollbarPolicy::Never;
         self.clip.set_constrain_vertical(true);
         self.clip.set_constrain_horizontal(false);
         self
     }
 
     pub fn disable_scrollbars(mut self) -> Self {
-        self.scroll_component.scrollbars_enabled = ScrollbarsEnabled::None;
+        self.h_policy = ScrollbarPolicy::Never;
+        self.v_policy = ScrollbarPolicy::Never;
         self
     }
 
     pub fn only_vertical_scrollbar(mut self) -> Self {
-        self.scroll_component.scrollbars_enabled = ScrollbarsEnabled::Vertical;
+        self.h_policy = ScrollbarPolicy::Never;
+        self.v_policy = ScrollbarPolicy::Always;
         self
     }
 
     pub fn only_horizontal_scrollbar(mut self) -> Self {
-        self.scroll_component.scrollbars_enabled = ScrollbarsEnabled::Horizontal;
+        self.h_policy = ScrollbarPolicy::Always;
+        self.v_policy = ScrollbarPolicy::Never;
+        self
+    }
+
+    /// Set the scrollbar policy for the horizontal axis. See [`ScrollbarPolicy`].
+    ///
+    /// [`ScrollbarPolicy`]: enum.ScrollbarPolicy.html
+    pub fn with_horizontal_policy(mut self, policy: ScrollbarPolicy) -> Self {
+        self.h_policy = policy;
+        self.clip
+            .set_constrain_horizontal(matches!(policy, ScrollbarPolicy::External));
+        self
+    }
+
+    /// Set the scrollbar policy for the vertical axis. See [`ScrollbarPolicy`].
+    ///
+    /// [`ScrollbarPolicy`]: enum.ScrollbarPolicy.html
+    pub fn with_vertical_policy(mut self, policy: ScrollbarPolicy) -> Self {
+        self.v_policy = policy;
+        self.clip
+            .set_constrain_vertical(matches!(policy, ScrollbarPolicy::External));
         self
     }
 
+    /// The `ScrollbarsEnabled` value that reflects the current policies and overflow state,
+    /// recomputed each layout and handed to `ScrollComponent` so it keeps drawing the bars the
+    /// same way it always has.
+    fn effective_scrollbars_enabled(&self) -> ScrollbarsEnabled {
+        let overflow = self.clip.content_size() - self.clip.viewport().rect.size();
+        let show_horizontal = match self.h_policy {
+            ScrollbarPolicy::Always => true,
+            ScrollbarPolicy::Automatic => overflow.width > 0.0,
+            ScrollbarPolicy::Never | ScrollbarPolicy::External => false,
+        };
+        let show_vertical = match self.v_policy {
+            ScrollbarPolicy::Always => true,
+            ScrollbarPolicy::Automatic => overflow.height > 0.0,
+            ScrollbarPolicy::Never | ScrollbarPolicy::External => false,
+        };
+        match (show_horizontal, show_vertical) {
+            (true, true) => ScrollbarsEnabled::Bidirectional,
+            (true, false) => ScrollbarsEnabled::Horizontal,
+            (false, true) => ScrollbarsEnabled::Vertical,
+            (false, false) => ScrollbarsEnabled::None,
+        }
+    }
+
     /// Returns a reference to the child widget.
     pub fn child(&self) -> &W {
         self.clip.child()
@@ -141,10 +447,56 @@ impl<T, W: Widget<T>> Scroll<T, W> {
     pub fn offset_for_axis(&self, axis: Axis) -> f64 {
         self.scroll_component.offset_for_axis(axis)
     }
+
+    /// Bind this axis's scroll position to a shared [`Adjustment`], keeping it synchronized with
+    /// any other widget holding the same `Rc<RefCell<Adjustment>>`.
+    ///
+    /// [`Adjustment`]: struct.Adjustment.html
+    pub fn with_external_adjustment(
+        mut self,
+        axis: Axis,
+        adjustment: Rc<RefCell<Adjustment>>,
+    ) -> Self {
+        self.external_adjustment = Some((axis, adjustment));
+        self
+    }
+
+    /// Enable flick/momentum scrolling: when a wheel burst or drag ends with nonzero velocity,
+    /// keep scrolling with exponentially decaying velocity rather than stopping instantly.
+    pub fn with_kinetic(mut self, kinetic: bool) -> Self {
+        self.kinetic = kinetic;
+        self
+    }
+
+    /// Attach a [`ScrollController`], letting app code (or another widget) drive this `Scroll`'s
+    /// position without routing offsets through the data tree, and keeping it in sync with any
+    /// other `Scroll` the same controller is attached to.
+    ///
+    /// [`ScrollController`]: struct.ScrollController.html
+    pub fn controller(mut self, controller: ScrollController) -> Self {
+        self.controller = Some(controller);
+        self
+    }
 }
 
 impl<T: Data, W: Widget<T>> Widget<T> for Scroll<T, W> {
     fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        if self.kinetic {
+            match event {
+                // Any new user input cancels an in-flight kinetic animation.
+                Event::MouseDown(_) | Event::KeyDown(_) => {
+                    self.velocity = Vec2::ZERO;
+                }
+                Event::Wheel(wheel) => {
+                    // Weighted average of recent deltas, so a burst of wheel ticks builds up a
+                    // velocity rather than each tick being treated independently.
+                    self.velocity = (self.velocity + wheel.wheel_delta) * 0.5;
+                    ctx.request_anim_frame();
+                }
+                _ => {}
+            }
+        }
+
         let scroll_component = &mut self.scroll_component;
         self.clip.with_port(|port| {
             scroll_component.event(port, ctx, event, env);
@@ -156,11 +508,79 @@ impl<T: Data, W: Widget<T>> Widget<T> for Scroll<T, W> {
         self.clip.with_port(|port| {
             scroll_component.handle_scroll(port, ctx, event, env);
         });
+
+        if let Some((axis, adjustment)) = &self.external_adjustment {
+            adjustment
+                .borrow_mut()
+                .set_value(self.scroll_component.offset_for_axis(*axis));
+        }
+
+        if let Some(controller) = &self.controller {
+            for request in controller.take_pending() {
+                match request {
+                    ScrollRequest::To(point) => {
+                        let delta = point.to_vec2() - self.offset();
+                        let _ = self.scroll_by(delta);
+                    }
+                    ScrollRequest::By(delta) => {
+                        let _ = self.scroll_by(delta);
+                    }
+                    ScrollRequest::ToVisible(region) => {
+                        let _ = self.scroll_to(region);
+                    }
+                    ScrollRequest::EnsureChildVisible(child) => {
+                        log::warn!(
+                            "ScrollController::ensure_child_visible({:?}) was requested, but \
+                             Scroll has no way to look up a descendant's rect by WidgetId in \
+                             this snapshot; ignoring it. See ScrollController::ensure_child_visible \
+                             for what's missing.",
+                            child
+                        );
+                    }
+                }
+            }
+
+            // A linked peer may have reported a newer shared offset since we last synced -
+            // adopt it on whichever axes `linked` selected. Gated on `last_synced_offset` so we
+            // don't immediately "follow" the value we ourselves just reported below.
+            let current = self.offset();
+            let target = controller.synced_offset(current);
+            if target != current && Some(target) != self.last_synced_offset {
+                let delta = target - current;
+                let _ = self.scroll_by(delta);
+                ctx.request_paint();
+            }
+
+            let new_offset = self.offset();
+            self.last_synced_offset = Some(new_offset);
+            controller.set_offset(new_offset);
+        }
     }
 
     fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
         self.scroll_component.lifecycle(ctx, event, env);
         self.clip.lifecycle(ctx, event, data, env);
+
+        if self.kinetic {
+            if let LifeCycle::AnimFrame(interval) = event {
+                if !env.get(theme::ANIMATIONS_ENABLED) {
+                    // No-op straight to the final (i.e. current) position instead of coasting.
+                    self.velocity = Vec2::ZERO;
+                } else if self.velocity.hypot2() > KINETIC_EPSILON_SQUARED {
+                    let dt = (*interval as f64) / 16_000_000.0;
+                    let _ = self.scroll_by(self.velocity * dt);
+                    self.velocity *= KINETIC_FRICTION.powf(dt);
+                    self.scroll_component
+                        .reset_scrollbar_fade(|d| ctx.request_timer(d), env);
+                    ctx.request_paint();
+                    if self.velocity.hypot2() > KINETIC_EPSILON_SQUARED {
+                        ctx.request_anim_frame();
+                    } else {
+                        self.velocity = Vec2::ZERO;
+                    }
+                }
+            }
+        }
     }
 
     fn update(&mut self, ctx: &mut UpdateCtx, old_data: &T, data: &T, env: &Env) {
@@ -174,13 +594,42 @@ impl<T: Data, W: Widget<T>> Widget<T> for Scroll<T, W> {
         let child_size = self.clip.layout(ctx, &bc, data, env);
         log_size_warnings(child_size);
 
-        let self_size = bc.constrain(child_size);
+        let mut wanted = bc.constrain(child_size);
+        let bar_width = env.get(theme::SCROLLBAR_WIDTH);
+        if let ScrollbarPolicy::Always = self.h_policy {
+            wanted.height += bar_width;
+        }
+        if let ScrollbarPolicy::Always = self.v_policy {
+            wanted.width += bar_width;
+        }
+        let self_size = bc.constrain(wanted);
+
+        self.scroll_component.scrollbars_enabled = self.effective_scrollbars_enabled();
+
         // The new size might have made the current scroll offset invalid. This makes it valid
         // again.
         let _ = self.scroll_by(Vec2::ZERO);
         if old_size != self_size {
-            self.scroll_component
-                .reset_scrollbar_fade(|d| ctx.request_timer(d), env);
+            if env.get(theme::ANIMATIONS_ENABLED) {
+                self.scroll_component
+                    .reset_scrollbar_fade(|d| ctx.request_timer(d), env);
+            } else {
+                // Skip scheduling the fade timer entirely, so bars don't animate in environments
+                // (automated UI tests, reduced-motion preferences, low-power contexts) where
+                // `theme::ANIMATIONS_ENABLED` has been turned off.
+                self.scroll_component.reset_scrollbar_fade(|_| {}, env);
+            }
+        }
+
+        if let Some((axis, adjustment)) = &self.external_adjustment {
+            let mut adjustment = adjustment.borrow_mut();
+            adjustment.set_extent(axis.major(self.clip.content_size()), axis.major(self_size));
+            let wanted = adjustment.value;
+            let should_repaint = adjustment.take_changed();
+            drop(adjustment);
+            if self.scroll_to_direction(*axis, wanted, self_size) || should_repaint {
+                ctx.request_paint();
+            }
         }
 
         self_size
@@ -191,6 +640,25 @@ impl<T: Data, W: Widget<T>> Widget<T> for Scroll<T, W> {
         self.scroll_component
             .draw_bars(ctx, &self.clip.viewport(), env);
     }
+
+    // Assumes `Widget::accessibility` has a no-op default, the same way `augmentation_raw` does,
+    // so only widgets with something to report need to override it.
+    fn accessibility(&mut self, ctx: &mut AccessCtx, data: &T, env: &Env) {
+        let viewport = self.clip.viewport();
+        ctx.push_node(AccessRole::ScrollBar, viewport.rect);
+        self.clip.accessibility(ctx, data, env);
+    }
+
+    // Assumes `Widget::after_layout` defaults to recursing into children, the same as
+    // `accessibility`'s default - see the `after_layout` module docs for the mechanism. The
+    // viewport is registered first, as the `Scroll`'s own hit region (including where `draw_bars`
+    // paints the scrollbars); the clipped child's hitboxes are registered after it, so wherever
+    // they overlap the viewport, scrollbar area included, the child's hitboxes are on top.
+    fn after_layout(&mut self, ctx: &mut AfterLayoutCtx, data: &T, env: &Env) {
+        let viewport = self.clip.viewport();
+        ctx.insert_hitbox(viewport.rect);
+        self.clip.after_layout(ctx, data, env);
+    }
 }
 
 fn log_size_warnings(size: Size) {
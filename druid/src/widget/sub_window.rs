@@ -1,45 +1,71 @@
 use crate::app::WindowConfig;
 use crate::command::sys::SUB_WINDOW_PARENT_TO_HOST;
 use crate::commands::SUB_WINDOW_HOST_TO_PARENT;
+use crate::widget::{AccessCtx, AccessRole, LensScopeTransfer, ScopeTransfer};
 use crate::{
-    BoxConstraints, Data, Env, Event, EventCtx, LayoutCtx, LifeCycle, LifeCycleCtx, PaintCtx,
+    BoxConstraints, Data, Env, Event, EventCtx, LayoutCtx, Lens, LifeCycle, LifeCycleCtx, PaintCtx,
     Point, Rect, Size, SubWindowRequirement, UpdateCtx, Widget, WidgetExt, WidgetId, WidgetPod,
     WindowId,
 };
-use std::ops::Deref;
 
-pub struct SubWindowHost<U, W: Widget<U>> {
+/// A sub-window's root widget, keeping its own `State` two-way synchronised with its parent's
+/// `In` via a [`ScopeTransfer`] - the same `read_input`/`write_back_input` contract that [`Scope`]
+/// uses to embed state within a single window. This lets a sub-window's data differ in shape
+/// from its parent's (e.g. a detached inspector showing a projection of the main window's data)
+/// while still staying live-synced, rather than only supporting a one-shot snapshot of identical
+/// types.
+///
+/// [`Scope`]: struct.Scope.html
+/// [`ScopeTransfer`]: trait.ScopeTransfer.html
+pub struct SubWindowHost<T: ScopeTransfer, W: Widget<T::State>> {
     id: WidgetId,
     parent_id: WidgetId,
     sync: bool,
-    data: U,
-    child: WidgetPod<U, W>,
+    transfer: T,
+    last_input: Option<T::In>,
+    data: T::State,
+    child: WidgetPod<T::State, W>,
 }
 
-impl<U, W: Widget<U>> SubWindowHost<U, W> {
-    pub fn new(id: WidgetId, port_id: WidgetId, sync: bool, data: U, widget: W) -> Self {
+impl<T: ScopeTransfer, W: Widget<T::State>> SubWindowHost<T, W> {
+    pub fn new(
+        id: WidgetId,
+        port_id: WidgetId,
+        sync: bool,
+        transfer: T,
+        data: T::State,
+        widget: W,
+    ) -> Self {
         SubWindowHost {
             id,
             parent_id: port_id,
             sync,
+            transfer,
+            last_input: None,
             data,
             child: WidgetPod::new(widget),
         }
     }
 
+    // Global `Env` flags such as `theme::ANIMATIONS_ENABLED` don't need any extra plumbing here:
+    // the sub-window is just another window sharing the app's base `Env`, so widgets inside it
+    // (e.g. a `Scroll`) read the same flag the same way their counterparts in the parent window
+    // do.
     pub fn make_requirement(
         parent_id: WidgetId,
         window_config: WindowConfig,
         sync: bool,
+        transfer: T,
         widget: W,
-        data: U,
+        data: T::State,
     ) -> SubWindowRequirement
     where
+        T: 'static,
         W: 'static,
-        U: Data,
     {
         let host_id = WidgetId::next();
-        let sub_window_host = SubWindowHost::new(host_id, parent_id, sync, data, widget).boxed();
+        let sub_window_host =
+            SubWindowHost::new(host_id, parent_id, sync, transfer, data, widget).boxed();
         SubWindowRequirement {
             host_id: if sync { Some(host_id) } else { None },
             sub_window_root: sub_window_host,
@@ -49,15 +75,42 @@ impl<U, W: Widget<U>> SubWindowHost<U, W> {
     }
 }
 
-impl<U: Data, W: Widget<U>> Widget<()> for SubWindowHost<U, W> {
+impl<In: Data, State: Data, L: Lens<State, In> + 'static, W: Widget<State> + 'static>
+    SubWindowHost<LensScopeTransfer<L, In, State>, W>
+{
+    /// Convenience constructor for the common case of syncing a sub-window through a `Lens` onto
+    /// a portion of the parent's data, rather than a hand-written [`ScopeTransfer`].
+    ///
+    /// [`ScopeTransfer`]: trait.ScopeTransfer.html
+    pub fn make_requirement_from_lens(
+        parent_id: WidgetId,
+        window_config: WindowConfig,
+        sync: bool,
+        lens: L,
+        widget: W,
+        data: State,
+    ) -> SubWindowRequirement {
+        Self::make_requirement(
+            parent_id,
+            window_config,
+            sync,
+            LensScopeTransfer::new(lens),
+            widget,
+            data,
+        )
+    }
+}
+
+impl<T: ScopeTransfer, W: Widget<T::State>> Widget<()> for SubWindowHost<T, W> {
     fn event(&mut self, ctx: &mut EventCtx, event: &Event, _data: &mut (), env: &Env) {
         match event {
             Event::Command(cmd) if self.sync && cmd.is(SUB_WINDOW_PARENT_TO_HOST) => {
                 if let Some(update) = cmd
                     .get_unchecked(SUB_WINDOW_PARENT_TO_HOST)
-                    .downcast_ref::<U>()
+                    .downcast_ref::<T::In>()
                 {
-                    self.data = update.deref().clone();
+                    self.transfer.read_input(&mut self.data, update, env);
+                    self.last_input = Some(update.clone());
                     let mut update_ctx = UpdateCtx {
                         state: ctx.state,
                         widget_state: ctx.widget_state,
@@ -80,10 +133,19 @@ impl<U: Data, W: Widget<U>> Widget<()> for SubWindowHost<U, W> {
                 };
                 self.child.update(&mut update_ctx, &self.data, env);
                 if self.sync && !old.same(&self.data) {
-                    ctx.submit_command(
-                        SUB_WINDOW_HOST_TO_PARENT.with(Box::new(self.data.clone())),
-                        self.parent_id,
-                    )
+                    if let Some(mut input) = self.last_input.clone() {
+                        self.transfer.write_back_input(&self.data, &mut input);
+                        self.last_input = Some(input.clone());
+                        ctx.submit_command(
+                            SUB_WINDOW_HOST_TO_PARENT.with(Box::new(input)),
+                            self.parent_id,
+                        )
+                    } else {
+                        log::warn!(
+                            "Sub window data changed before any input was received from its \
+                            parent; skipping write-back since there is nothing to base it on."
+                        );
+                    }
                 }
             }
         }
@@ -112,6 +174,11 @@ impl<U: Data, W: Widget<U>> Widget<()> for SubWindowHost<U, W> {
         self.child.paint_raw(ctx, &self.data, env);
     }
 
+    fn accessibility(&mut self, ctx: &mut AccessCtx, _data: &(), env: &Env) {
+        ctx.push_node(AccessRole::Window, self.child.layout_rect());
+        self.child.accessibility(ctx, &self.data, env);
+    }
+
     fn id(&self) -> Option<WidgetId> {
         Some(self.id)
     }
@@ -15,6 +15,7 @@
 //! A widget that can switch between one of many views, hiding the inactive ones.
 //!
 
+use std::any::Any;
 use std::collections::HashMap;
 use std::fmt::Debug;
 use std::hash::Hash;
@@ -24,14 +25,17 @@ use std::rc::Rc;
 use crate::kurbo::Line;
 use crate::piet::RenderContext;
 
-use crate::widget::{Axis, CrossAxisAlignment, Flex, Label, LensScopeTransfer, Scope, ScopePolicy};
+use crate::widget::{
+    AccessCtx, AccessRole, Axis, CrossAxisAlignment, Flex, Label, LensScopeTransfer, Scope,
+    ScopePolicy,
+};
 use crate::{
-    theme, Affine, BoxConstraints, Color, Data, Env, Event, EventCtx, Insets, LayoutCtx, Lens,
-    LifeCycle, LifeCycleCtx, PaintCtx, Point, Rect, SingleUse, Size, UpdateCtx, Widget, WidgetExt,
-    WidgetPod,
+    theme, Affine, BoxConstraints, Color, Data, Env, Event, EventCtx, Insets, KbKey, LayoutCtx,
+    Lens, LifeCycle, LifeCycleCtx, PaintCtx, Point, Rect, Selector, SingleUse, Size, UpdateCtx,
+    Widget, WidgetExt, WidgetPod,
 };
-use std::slice::Iter;
 use std::iter::FlatMap;
+use std::slice::Iter;
 
 type TabsScope<TFD> = Scope<TabsScopePolicy<TFD>, Box<dyn Widget<TabsState<TFD>>>>;
 type TabBodyPod<TFD> = WidgetPod<<TFD as TabsFromData>::T, <TFD as TabsFromData>::BodyWidget>;
@@ -40,9 +44,9 @@ type TabIndex = usize;
 
 const MILLIS: u64 = 1_000_000; // Number of nanos
 
-pub struct TabInfo{
+pub struct TabInfo {
     pub name: String,
-    pub can_close: bool
+    pub can_close: bool,
 }
 
 impl TabInfo {
@@ -51,7 +55,6 @@ impl TabInfo {
     }
 }
 
-
 /// A policy that determines how a Tabs instance derives its tabs from its app data
 pub trait TabsFromData: Data {
     /// A type representing a set of tabs. Its expected to be cheap to derive and compare.
@@ -65,7 +68,7 @@ pub trait TabsFromData: Data {
     type Build;
 
     /// The input data that will a) be used to derive the tab and b) also be the input data of all the child widgets.
-    type T : Data;
+    type T: Data;
 
     /// The common type for all body widgets in this set of tabs.
     type BodyWidget: Widget<Self::T>;
@@ -73,11 +76,11 @@ pub trait TabsFromData: Data {
     /// Derive the set of tabs from the data.
     fn tabs(&self, data: &Self::T) -> Self::TabSet;
 
-    fn tabs_changed(&self, old_data: &Self::T, data: &Self::T) -> Option<Self::TabSet>{
+    fn tabs_changed(&self, old_data: &Self::T, data: &Self::T) -> Option<Self::TabSet> {
         let cur = self.tabs(data);
-        if cur != self.tabs(old_data){
+        if cur != self.tabs(old_data) {
             Some(cur)
-        }else{
+        } else {
             None
         }
     }
@@ -92,10 +95,58 @@ pub trait TabsFromData: Data {
     fn body_from_key(&self, key: Self::TabKey, data: &Self::T) -> Option<Self::BodyWidget>;
 
     #[allow(unused_variables)]
-    fn close_tab(&self, key: Self::TabKey, data: &mut Self::T){
-
+    fn close_tab(&self, key: Self::TabKey, data: &mut Self::T) {}
+
+    /// Returns a new tab set with `key` removed, for implementations whose `TabSet` fully
+    /// determines their own tab identities (e.g. a numeric range derived straight from `T`, the
+    /// way the `tabs` example's `NumberedTabs` works) rather than needing a targeted mutation
+    /// through [`close_tab`]. [`TabBar`]'s close glyph tries this first, writing a `Some` result
+    /// back via [`apply_tab_set`], and then calls [`close_tab`] as well, whatever this returned;
+    /// the default is `None`, so a policy that doesn't
+    /// override it (e.g. [`StaticTabs`], [`VecTabs`]) simply relies on [`close_tab`] alone, as
+    /// before.
+    ///
+    /// [`close_tab`]: trait.TabsFromData.html#method.close_tab
+    /// [`apply_tab_set`]: trait.TabsFromData.html#method.apply_tab_set
+    /// [`TabBar`]: struct.TabBar.html
+    /// [`StaticTabs`]: struct.StaticTabs.html
+    /// [`VecTabs`]: struct.VecTabs.html
+    #[allow(unused_variables)]
+    fn close_key(&self, key: Self::TabKey, set: Self::TabSet) -> Option<Self::TabSet> {
+        None
     }
 
+    /// Write a `TabSet` produced by [`close_key`] back into `data`, for implementations whose
+    /// `TabSet` doubles as (or derives cheaply back into) their own data. Defaults to a no-op,
+    /// since [`close_key`]'s default never produces a `Some` for a policy that doesn't override
+    /// both together.
+    ///
+    /// [`close_key`]: trait.TabsFromData.html#method.close_key
+    #[allow(unused_variables)]
+    fn apply_tab_set(&self, set: Self::TabSet, data: &mut Self::T) {}
+
+    /// Create a new tab at runtime (e.g. from the "+" button [`Tabs::with_add_button`] adds to
+    /// `TabBar`), selecting it once added. The default is a no-op, the same way `close_tab`'s is
+    /// - implementations whose data has somewhere to put a new tab (e.g. a `Vec` of tab data,
+    /// like [`VecTabs`]) should override this; `TabBar::ensure_tabs`/`TabsBody::make_tabs` already
+    /// reconcile by key, so a freshly added tab appears after the next `update`. [`StaticTabs`]
+    /// keeps the inherited no-op - see its own doc note for why it can't do this itself.
+    ///
+    /// [`Tabs::with_add_button`]: struct.Tabs.html#method.with_add_button
+    /// [`VecTabs`]: struct.VecTabs.html
+    /// [`StaticTabs`]: struct.StaticTabs.html
+    #[allow(unused_variables)]
+    fn add_tab_runtime(&self, data: &mut Self::T) {}
+
+    /// Move the tab identified by `key` so it sits at `to_idx` in the tab order. Implementations
+    /// whose data has an inherent order (e.g. backed by a `Vec`) should override this to support
+    /// drag-to-reorder in [`TabBar`]; the default is a no-op, so `TabBar` simply won't reorder
+    /// live and the drag will spring back once `tabs_changed` reports no change.
+    ///
+    /// [`TabBar`]: struct.TabBar.html
+    #[allow(unused_variables)]
+    fn reorder_tab(&self, key: Self::TabKey, to_idx: usize, data: &mut Self::T) {}
+
     #[allow(unused_variables)]
     // This should only be implemented if supporting AddTab - possibly only StaticTabs needs to.
     fn build(build: Self::Build) -> Self {
@@ -121,7 +172,7 @@ impl<T> StaticTabs<T> {
 #[derive(Data, Copy, Clone, Debug, PartialOrd, PartialEq, Eq, Hash)]
 pub struct STabKey(pub usize);
 
-impl <T: Data> Data for StaticTabs<T>{
+impl<T: Data> Data for StaticTabs<T> {
     fn same(&self, _other: &Self) -> bool {
         // Changing the tabs after construction shouldn't be possible for static tabs
         // It seems pointless to compare them
@@ -130,6 +181,13 @@ impl <T: Data> Data for StaticTabs<T>{
 }
 
 impl<T: Data> TabsFromData for StaticTabs<T> {
+    // `add_tab_runtime` (and `reorder_tab`) keep the default no-op: the tab list here lives in
+    // `Rc<Vec<InitialTab<T>>>`, outside of `T`, so there's nowhere in `&mut T` to push a new tab
+    // into - and even giving this `&self` method interior mutability (a `RefCell`) wouldn't help,
+    // since `Data::same` can't see through it (a clone shares the same `Rc`, so an old snapshot
+    // would observe the mutation too, breaking the update-skipping it's relied on for). `VecTabs`
+    // is the real implementation for a dynamic policy: its tab list lives inside its own `T`,
+    // where ordinary `Vec`-derived `Data` equality already works.
     type TabSet = ();
     type TabKey = STabKey;
     type Build = Vec<InitialTab<T>>;
@@ -164,7 +222,11 @@ impl<T: Data> TabsFromData for StaticTabs<T> {
 }
 
 pub trait AddTab: TabsFromData {
-    fn add_tab(tabs: &mut Self::Build, name: impl Into<String>, child: impl Widget<Self::T> + 'static);
+    fn add_tab(
+        tabs: &mut Self::Build,
+        name: impl Into<String>,
+        child: impl Widget<Self::T> + 'static,
+    );
 }
 
 impl<T: Data> AddTab for StaticTabs<T> {
@@ -173,6 +235,105 @@ impl<T: Data> AddTab for StaticTabs<T> {
     }
 }
 
+/// A dynamic [`TabsFromData`] policy whose tabs are plain `Vec<I>` data living directly inside
+/// the owning `T = Vec<I>`, rather than held outside it the way [`StaticTabs`] does - that's what
+/// lets [`TabsFromData::add_tab_runtime`]/[`TabsFromData::reorder_tab`]/
+/// [`TabsFromData::close_tab`] mutate the tab list for real through the `&mut Self::T` they're
+/// already given, with ordinary `Vec`-derived `Data` equality picking the change up (unlike
+/// [`StaticTabs`]'s externally-held list, which its `&self`-only methods could mutate but
+/// `Data::same` could never observe). A fresh `BodyWidget` is built from each tab's item by
+/// `make_body`, the same factory-closure pattern [`List`] uses for its children; tabs are keyed
+/// by index, so - like [`List`]'s own reconciliation - a widget's internal state isn't guaranteed
+/// to follow its item across a reorder.
+///
+/// [`StaticTabs`]: struct.StaticTabs.html
+/// [`TabsFromData::add_tab_runtime`]: trait.TabsFromData.html#method.add_tab_runtime
+/// [`List`]: struct.List.html
+pub struct VecTabs<I, W> {
+    make_name: Rc<dyn Fn(&I, usize) -> String>,
+    make_body: Rc<dyn Fn(&I, usize) -> W>,
+    new_tab: Rc<dyn Fn(usize) -> I>,
+}
+
+impl<I, W> Clone for VecTabs<I, W> {
+    fn clone(&self) -> Self {
+        VecTabs {
+            make_name: self.make_name.clone(),
+            make_body: self.make_body.clone(),
+            new_tab: self.new_tab.clone(),
+        }
+    }
+}
+
+impl<I: Data, W: Widget<I> + 'static> VecTabs<I, W> {
+    /// `make_name`/`make_body` are called with a tab's item and its current index each time a
+    /// label or body widget is needed; `new_tab` is called with the index a runtime-added tab
+    /// will occupy (see [`TabsFromData::add_tab_runtime`]) to produce that tab's initial item.
+    ///
+    /// [`TabsFromData::add_tab_runtime`]: trait.TabsFromData.html#method.add_tab_runtime
+    pub fn new(
+        make_name: impl Fn(&I, usize) -> String + 'static,
+        make_body: impl Fn(&I, usize) -> W + 'static,
+        new_tab: impl Fn(usize) -> I + 'static,
+    ) -> Self {
+        VecTabs {
+            make_name: Rc::new(make_name),
+            make_body: Rc::new(make_body),
+            new_tab: Rc::new(new_tab),
+        }
+    }
+}
+
+impl<I: Data, W: Widget<I> + 'static> Data for VecTabs<I, W> {
+    fn same(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.make_name, &other.make_name)
+            && Rc::ptr_eq(&self.make_body, &other.make_body)
+            && Rc::ptr_eq(&self.new_tab, &other.new_tab)
+    }
+}
+
+impl<I: Data, W: Widget<I> + 'static> TabsFromData for VecTabs<I, W> {
+    type TabSet = usize;
+    type TabKey = usize;
+    type Build = ();
+    type T = Vec<I>;
+    type BodyWidget = W;
+
+    fn tabs(&self, data: &Self::T) -> Self::TabSet {
+        data.len()
+    }
+
+    fn keys_from_set(&self, set: Self::TabSet, _data: &Self::T) -> Vec<Self::TabKey> {
+        (0..set).collect()
+    }
+
+    fn info_from_key(&self, key: Self::TabKey, data: &Self::T) -> TabInfo {
+        TabInfo::new((self.make_name)(&data[key], key), true)
+    }
+
+    fn body_from_key(&self, key: Self::TabKey, data: &Self::T) -> Option<Self::BodyWidget> {
+        data.get(key).map(|item| (self.make_body)(item, key))
+    }
+
+    fn close_tab(&self, key: Self::TabKey, data: &mut Self::T) {
+        if key < data.len() {
+            data.remove(key);
+        }
+    }
+
+    fn add_tab_runtime(&self, data: &mut Self::T) {
+        let idx = data.len();
+        data.push((self.new_tab)(idx));
+    }
+
+    fn reorder_tab(&self, key: Self::TabKey, to_idx: usize, data: &mut Self::T) {
+        if key != to_idx && key < data.len() && to_idx < data.len() {
+            let item = data.remove(key);
+            data.insert(to_idx, item);
+        }
+    }
+}
+
 #[derive(Clone, Lens, Data)]
 pub struct TabsState<TFD: TabsFromData> {
     pub inner: TFD::T,
@@ -190,23 +351,212 @@ impl<TFD: TabsFromData> TabsState<TFD> {
     }
 }
 
+/// Submit to a `Running` [`Tabs`] widget's id to add a tab at runtime, the same as clicking its
+/// "+" button (see [`Tabs::with_add_button`]): calls [`TabsFromData::add_tab_runtime`] and
+/// selects the tab it creates. Like the "+" button, this only grows the tab set for a
+/// [`TabsFromData`] whose [`add_tab_runtime`] override actually has somewhere in `T` to put the
+/// new tab - [`StaticTabs`] keeps its tab list outside `T`, so it stays a no-op there.
+///
+/// [`Tabs`]: struct.Tabs.html
+/// [`Tabs::with_add_button`]: struct.Tabs.html#method.with_add_button
+/// [`TabsFromData`]: trait.TabsFromData.html
+/// [`TabsFromData::add_tab_runtime`]: trait.TabsFromData.html#method.add_tab_runtime
+/// [`add_tab_runtime`]: trait.TabsFromData.html#method.add_tab_runtime
+/// [`StaticTabs`]: struct.StaticTabs.html
+pub const ADD_TAB: Selector<()> = Selector::new("druid-builtin.tabs.add-tab");
+
+/// Submit to a `Running` [`Tabs`] widget's id to select a tab by index at runtime. Out-of-range
+/// indices are clamped to the last tab.
+///
+/// [`Tabs`]: struct.Tabs.html
+pub const SELECT_TAB: Selector<usize> = Selector::new("druid-builtin.tabs.select-tab");
+
+/// Submit to a `Running` [`Tabs`] widget's id to close a tab by key at runtime, the same as
+/// clicking its close glyph (see [`TabInfo::can_close`]). The payload is a type-erased
+/// `TFD::TabKey` - since `TFD` varies per `Tabs` instantiation it can't be named in a single
+/// free-standing `Selector<T>`, so callers box it up the same way [`SUB_WINDOW_PARENT_TO_HOST`]
+/// smuggles a sub-window's `In` type across a command; a payload that doesn't downcast to this
+/// instance's `TabKey` is logged and ignored.
+///
+/// [`Tabs`]: struct.Tabs.html
+/// [`TabInfo::can_close`]: struct.TabInfo.html#structfield.can_close
+/// [`SUB_WINDOW_PARENT_TO_HOST`]: ../command/sys/constant.SUB_WINDOW_PARENT_TO_HOST.html
+pub const REMOVE_TAB: Selector<SingleUse<Box<dyn Any>>> =
+    Selector::new("druid-builtin.tabs.remove-tab");
+
+/// Submit to a `Running` [`Tabs`] widget's id to move its selection to the next tab, wrapping
+/// around at the end - the same move [`Tabs`]'s own `Ctrl+Tab` handling makes, exposed so an app
+/// can bind its own shortcut or menu item to it.
+///
+/// [`Tabs`]: struct.Tabs.html
+pub const SELECT_NEXT_TAB: Selector<()> = Selector::new("druid-builtin.tabs.select-next-tab");
+
+/// Submit to a `Running` [`Tabs`] widget's id to move its selection to the previous tab, wrapping
+/// around at the start - the same move [`Tabs`]'s own `Ctrl+Shift+Tab` handling makes.
+///
+/// [`Tabs`]: struct.Tabs.html
+pub const SELECT_PREV_TAB: Selector<()> = Selector::new("druid-builtin.tabs.select-prev-tab");
+
+/// Move `state.selected` by `dir` tabs, wrapping around at either end - shared by [`Tabs`]'s
+/// `Ctrl+Tab` handling and the [`SELECT_NEXT_TAB`]/[`SELECT_PREV_TAB`] commands. [`TabBar`]'s
+/// arrow-key handling makes the same wrapping move with its own copy of this arithmetic.
+///
+/// [`Tabs`]: struct.Tabs.html
+/// [`TabBar`]: struct.TabBar.html
+fn move_tab_selection<TFD: TabsFromData>(state: &mut TabsState<TFD>, dir: isize) {
+    let len = state
+        .tabs_from_data
+        .keys_from_set(state.tabs_from_data.tabs(&state.inner), &state.inner)
+        .len() as isize;
+    if len > 0 {
+        state.selected = (state.selected as isize + dir).rem_euclid(len) as usize;
+    }
+}
+
+/// Close the currently selected tab, the same as [`Ctrl+W`] does in [`Tabs::event`] when
+/// [`Tabs::with_closable`] is set.
+///
+/// [`Ctrl+W`]: struct.Tabs.html
+/// [`Tabs::event`]: struct.Tabs.html
+/// [`Tabs::with_closable`]: struct.Tabs.html#method.with_closable
+fn close_selected_tab<TFD: TabsFromData>(state: &mut TabsState<TFD>) {
+    let set = state.tabs_from_data.tabs(&state.inner);
+    let keys = state.tabs_from_data.keys_from_set(set, &state.inner);
+    if let Some(key) = keys.get(state.selected).cloned() {
+        close_tab_and_select(state, key);
+    }
+}
+
+/// Add a tab via [`TabsFromData::add_tab_runtime`] and select the one it created, if any - shared
+/// by the add-button's `on_click` (built in [`TabBar::new`]) and the [`ADD_TAB`] command.
+///
+/// [`TabsFromData::add_tab_runtime`]: trait.TabsFromData.html#method.add_tab_runtime
+/// [`TabBar::new`]: struct.TabBar.html#method.new
+fn add_runtime_tab_and_select<TFD: TabsFromData>(state: &mut TabsState<TFD>) {
+    let before_len = state
+        .tabs_from_data
+        .keys_from_set(state.tabs_from_data.tabs(&state.inner), &state.inner)
+        .len();
+
+    state.tabs_from_data.add_tab_runtime(&mut state.inner);
+
+    let after = state.tabs_from_data.tabs(&state.inner);
+    let after_len = state
+        .tabs_from_data
+        .keys_from_set(after, &state.inner)
+        .len();
+    if after_len > before_len {
+        state.selected = after_len - 1;
+    }
+}
+
+/// Close the tab identified by `key` - via [`TabsFromData::close_key`]/`apply_tab_set` if the
+/// policy overrides them, then via [`TabsFromData::close_tab`] either way - then repair `selected`
+/// so it keeps pointing at the same visual tab (or clamps into range if the closed tab was last).
+/// Shared by the close glyph's `on_click` (built in [`TabBar::ensure_tabs`]) and the
+/// [`REMOVE_TAB`] command.
+///
+/// [`TabsFromData::close_key`]: trait.TabsFromData.html#method.close_key
+/// [`TabsFromData::close_tab`]: trait.TabsFromData.html#method.close_tab
+/// [`TabBar::ensure_tabs`]: struct.TabBar.html#method.ensure_tabs
+fn close_tab_and_select<TFD: TabsFromData>(state: &mut TabsState<TFD>, key: TFD::TabKey) {
+    let before = state.tabs_from_data.tabs(&state.inner);
+    let closed_idx = state
+        .tabs_from_data
+        .keys_from_set(before, &state.inner)
+        .iter()
+        .position(|k| *k == key);
+
+    let set = state.tabs_from_data.tabs(&state.inner);
+    if let Some(new_set) = state.tabs_from_data.close_key(key.clone(), set) {
+        state
+            .tabs_from_data
+            .apply_tab_set(new_set, &mut state.inner);
+    }
+    state.tabs_from_data.close_tab(key, &mut state.inner);
+
+    if let Some(closed_idx) = closed_idx {
+        if closed_idx <= state.selected && state.selected > 0 {
+            state.selected -= 1;
+        }
+    }
+    let after = state.tabs_from_data.tabs(&state.inner);
+    let after_len = state
+        .tabs_from_data
+        .keys_from_set(after, &state.inner)
+        .len();
+    if state.selected >= after_len {
+        state.selected = after_len.saturating_sub(1);
+    }
+}
+
 pub struct TabBar<TFD: TabsFromData> {
     axis: Axis,
     cross: CrossAxisAlignment,
     orientation: TabOrientation,
     tabs: Vec<(TFD::TabKey, TabBarPod<TFD>)>,
     hot: Option<TabIndex>,
+    // The key is tracked (rather than just the index) so that as reordering shuffles `tabs`
+    // around us, we keep following the same tab the user actually grabbed.
+    dragging: Option<TFD::TabKey>,
+    // A trailing "+" affordance, not part of `tabs` since it has no `TabKey` of its own.
+    add_button: Option<TabBarPod<TFD>>,
+    // How far the tab strip has been scrolled along `axis` to bring overflowing tabs into view.
+    scroll_offset: f64,
+    // The largest `scroll_offset` can be before the end of the strip would show past its content;
+    // 0 when everything fits and no scrolling is needed.
+    max_scroll_offset: f64,
+    // Force every tab to render a close glyph, regardless of its own `TabInfo::can_close` -
+    // set via `Tabs::with_closable`.
+    closable: bool,
+    // How to handle the tabs overflowing the space available - set via `Tabs::with_overflow`.
+    overflow: TabOverflow,
+    // Set by `update` when `selected` changes, so the next `layout` scrolls it into view.
+    scroll_to_selected: bool,
     phantom_tfd: PhantomData<TFD>,
 }
 
 impl<TFD: TabsFromData> TabBar<TFD> {
-    pub fn new(axis: Axis, cross: CrossAxisAlignment, orientation: TabOrientation) -> Self {
+    pub fn new(
+        axis: Axis,
+        cross: CrossAxisAlignment,
+        orientation: TabOrientation,
+        show_add_button: bool,
+        closable: bool,
+        overflow: TabOverflow,
+    ) -> Self {
+        let add_button = if show_add_button {
+            Some(WidgetPod::new(
+                orientation.rotate_and_box(
+                    Label::<TabsState<TFD>>::new("+")
+                        .with_font("Gill Sans".to_string())
+                        .with_text_color(Color::WHITE)
+                        .with_text_size(12.0)
+                        .padding(Insets::uniform_xy(9., 5.))
+                        .on_click(|_ctx, data: &mut TabsState<TFD>, _env| {
+                            add_runtime_tab_and_select(data);
+                        }),
+                    axis,
+                    cross,
+                ),
+            ))
+        } else {
+            None
+        };
+
         TabBar {
             axis,
             cross,
             orientation,
             tabs: vec![],
             hot: None,
+            dragging: None,
+            add_button,
+            scroll_offset: 0.,
+            max_scroll_offset: 0.,
+            closable,
+            overflow,
+            scroll_to_selected: false,
             phantom_tfd: Default::default(),
         }
     }
@@ -230,7 +580,8 @@ impl<TFD: TabsFromData> TabBar<TFD> {
 
     fn ensure_tabs(&mut self, data: &TabsState<TFD>, tab_set: TFD::TabSet) {
         // Borrow checker fun
-        let (orientation, axis, cross) = (self.orientation, self.axis, self.cross);
+        let (orientation, axis, cross, closable) =
+            (self.orientation, self.axis, self.cross, self.closable);
         let finish = |w| WidgetPod::new(orientation.rotate_and_box(w, axis, cross));
         let finish2 = |w| WidgetPod::new(orientation.rotate_and_box(w, axis, cross));
 
@@ -248,15 +599,17 @@ impl<TFD: TabsFromData> TabBar<TFD> {
                     .with_text_size(12.0)
                     .padding(Insets::uniform_xy(9., 5.));
 
-                if info.can_close{
+                if info.can_close || closable {
                     let c_key = key.clone();
                     let row = Flex::row()
                         .with_child(label)
-                        .with_child(Label::new( "â“§" ).on_click( move |_ctx, data : &mut TabsState<TFD>, _env|{
-                            data.tabs_from_data.close_tab(c_key.clone(),  &mut data.inner);
-                        }));
+                        .with_child(Label::new("â“§").on_click(
+                            move |_ctx, data: &mut TabsState<TFD>, _env| {
+                                close_tab_and_select(data, c_key.clone());
+                            },
+                        ));
                     finish(row)
-                }else{
+                } else {
                     finish2(label)
                 }
             },
@@ -265,17 +618,14 @@ impl<TFD: TabsFromData> TabBar<TFD> {
 }
 
 impl<TFD: TabsFromData> Widget<TabsState<TFD>> for TabBar<TFD> {
-    fn event(
-        &mut self,
-        ctx: &mut EventCtx,
-        event: &Event,
-        data: &mut TabsState<TFD>,
-        env: &Env,
-    ) {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut TabsState<TFD>, env: &Env) {
         match event {
             Event::MouseDown(e) => {
                 if let Some(idx) = self.find_idx(e.pos) {
                     data.selected = idx;
+                    self.dragging = self.tabs.get(idx).map(|(key, _)| key.clone());
+                    ctx.set_active(true);
+                    ctx.request_focus();
                 }
             }
             Event::MouseMove(e) => {
@@ -288,6 +638,53 @@ impl<TFD: TabsFromData> Widget<TabsState<TFD>> for TabBar<TFD> {
                     self.hot = new_hot;
                     ctx.request_paint();
                 }
+
+                if ctx.is_active() {
+                    if let (Some(dragged_key), Some(to_idx)) =
+                        (self.dragging.clone(), self.find_idx(e.pos))
+                    {
+                        data.tabs_from_data
+                            .reorder_tab(dragged_key, to_idx, &mut data.inner);
+                    }
+                }
+            }
+            Event::MouseUp(_) => {
+                if ctx.is_active() {
+                    self.dragging = None;
+                    ctx.set_active(false);
+                }
+            }
+            Event::Wheel(wheel_event) if self.max_scroll_offset > 0. => {
+                let delta = match self.axis {
+                    Axis::Horizontal => wheel_event.wheel_delta.x,
+                    Axis::Vertical => wheel_event.wheel_delta.y,
+                };
+                let new_offset = (self.scroll_offset + delta)
+                    .max(0.)
+                    .min(self.max_scroll_offset);
+                if new_offset != self.scroll_offset {
+                    self.scroll_offset = new_offset;
+                    ctx.set_handled();
+                    ctx.request_layout();
+                }
+            }
+            Event::KeyDown(key_event) if !self.tabs.is_empty() && ctx.is_focused() => {
+                // Left/Up moves to the previous tab, Right/Down to the next, wrapping around at
+                // either end - the `Tabbable` next/prev-tab model. Gated on `ctx.is_focused()` so
+                // this only fires when the tab strip itself holds focus, not when focus is on an
+                // arbitrary descendant inside the selected tab's body; `Ctrl+Tab`/`Ctrl+Shift+Tab`
+                // are handled once, globally, by `Tabs::event`, so they aren't repeated here.
+                let dir = match &key_event.key {
+                    KbKey::ArrowLeft | KbKey::ArrowUp => Some(-1isize),
+                    KbKey::ArrowRight | KbKey::ArrowDown => Some(1isize),
+                    _ => None,
+                };
+                if let Some(dir) = dir {
+                    let len = self.tabs.len() as isize;
+                    data.selected = (data.selected as isize + dir).rem_euclid(len) as usize;
+                    ctx.set_handled();
+                    ctx.request_paint();
+                }
             }
             _ => {}
         }
@@ -295,6 +692,9 @@ impl<TFD: TabsFromData> Widget<TabsState<TFD>> for TabBar<TFD> {
         for (_, tab) in self.tabs.iter_mut() {
             tab.event(ctx, event, data, env);
         }
+        if let Some(add_button) = &mut self.add_button {
+            add_button.event(ctx, event, data, env);
+        }
     }
 
     fn lifecycle(
@@ -309,11 +709,15 @@ impl<TFD: TabsFromData> Widget<TabsState<TFD>> for TabBar<TFD> {
             self.ensure_tabs(data, init_set);
             ctx.children_changed();
             ctx.request_layout();
+            ctx.register_for_focus();
         }
 
-        for  (_, tab) in self.tabs.iter_mut() {
+        for (_, tab) in self.tabs.iter_mut() {
             tab.lifecycle(ctx, event, data, env);
         }
+        if let Some(add_button) = &mut self.add_button {
+            add_button.lifecycle(ctx, event, data, env);
+        }
     }
 
     fn update(
@@ -330,8 +734,10 @@ impl<TFD: TabsFromData> Widget<TabsState<TFD>> for TabBar<TFD> {
             self.ensure_tabs(data, tab_set);
             ctx.children_changed();
             ctx.request_layout();
-        } else if old_data.selected != data.selected {
-            ctx.request_paint();
+        }
+        if old_data.selected != data.selected {
+            self.scroll_to_selected = true;
+            ctx.request_layout();
         }
     }
 
@@ -354,16 +760,117 @@ impl<TFD: TabsFromData> Widget<TabsState<TFD>> for TabBar<TFD> {
             major += self.axis.major(size);
             minor = f64::max(minor, self.axis.minor(size));
         }
-        // Now go back through to reset the minors
-        for (_, tab) in self.tabs.iter_mut() {
-            let rect = tab.layout_rect();
-            let rect = rect.with_size(self.axis.pack(self.axis.major(rect.size()), minor));
-            tab.set_layout_rect(ctx, data, env, rect);
+        if let Some(add_button) = &mut self.add_button {
+            let size = add_button.layout(ctx, bc, data, env);
+            add_button.set_layout_rect(
+                ctx,
+                data,
+                env,
+                Rect::from_origin_size(self.axis.pack(major, 0.), size),
+            );
+            major += self.axis.major(size);
+            minor = f64::max(minor, self.axis.minor(size));
         }
 
-        let wanted = self
-            .axis
-            .pack(f64::max(major, self.axis.major(bc.max())), minor);
+        let viewport_major = self.axis.major(bc.max());
+        let overflowing = major > viewport_major;
+
+        match self.overflow {
+            TabOverflow::Scroll => {
+                // Tabs overflow the viewport once their combined major extent exceeds what we
+                // were given; scroll rather than clip in that case, keeping the offset in bounds
+                // as the tab set (and so the content extent) changes.
+                self.max_scroll_offset = (major - viewport_major).max(0.);
+                if self.scroll_to_selected {
+                    if let Some((_, tab)) = self.tabs.get(data.selected) {
+                        let (near, far) = self.axis.major_span(&tab.layout_rect());
+                        if far - near <= viewport_major {
+                            if near < self.scroll_offset {
+                                self.scroll_offset = near;
+                            } else if far > self.scroll_offset + viewport_major {
+                                self.scroll_offset = far - viewport_major;
+                            }
+                        }
+                    }
+                }
+                self.scroll_offset = self.scroll_offset.min(self.max_scroll_offset).max(0.);
+
+                // Now go back through to reset the minors and shift by the scroll offset
+                for (_, tab) in self.tabs.iter_mut() {
+                    let rect = tab.layout_rect();
+                    let new_major = self.axis.major_pos(rect.origin()) - self.scroll_offset;
+                    let rect = Rect::from_origin_size(
+                        self.axis.pack(new_major, 0.),
+                        self.axis.pack(self.axis.major(rect.size()), minor),
+                    );
+                    tab.set_layout_rect(ctx, data, env, rect);
+                }
+                if let Some(add_button) = &mut self.add_button {
+                    let rect = add_button.layout_rect();
+                    let new_major = self.axis.major_pos(rect.origin()) - self.scroll_offset;
+                    let rect = Rect::from_origin_size(
+                        self.axis.pack(new_major, 0.),
+                        self.axis.pack(self.axis.major(rect.size()), minor),
+                    );
+                    add_button.set_layout_rect(ctx, data, env, rect);
+                }
+            }
+            TabOverflow::Compress => {
+                // No scrolling in this mode - instead give every tab an equal share of the
+                // viewport and re-layout it into that narrower space, so long labels shrink
+                // (e.g. via `Label`'s own eliding) rather than overflowing.
+                self.max_scroll_offset = 0.;
+                self.scroll_offset = 0.;
+                if overflowing {
+                    let count = self.tabs.len() + self.add_button.is_some() as usize;
+                    let share = viewport_major / count.max(1) as f64;
+                    let minor_max = self.axis.minor(bc.max());
+                    let tab_bc = BoxConstraints::new(Size::ZERO, self.axis.pack(share, minor_max));
+                    let mut pos = 0.;
+                    minor = 0.;
+                    for (_, tab) in self.tabs.iter_mut() {
+                        let size = tab.layout(ctx, &tab_bc, data, env);
+                        tab.set_layout_rect(
+                            ctx,
+                            data,
+                            env,
+                            Rect::from_origin_size(self.axis.pack(pos, 0.), size),
+                        );
+                        pos += self.axis.major(size);
+                        minor = f64::max(minor, self.axis.minor(size));
+                    }
+                    if let Some(add_button) = &mut self.add_button {
+                        let size = add_button.layout(ctx, &tab_bc, data, env);
+                        add_button.set_layout_rect(
+                            ctx,
+                            data,
+                            env,
+                            Rect::from_origin_size(self.axis.pack(pos, 0.), size),
+                        );
+                        pos += self.axis.major(size);
+                        minor = f64::max(minor, self.axis.minor(size));
+                    }
+                    major = pos;
+                    // The second pass above already set each minor individually as it went; make
+                    // a final pass so they all match the tallest, the same as the other branch.
+                    for (_, tab) in self.tabs.iter_mut() {
+                        let rect = tab.layout_rect();
+                        tab.set_layout_rect(
+                            ctx,
+                            data,
+                            env,
+                            Rect::from_origin_size(
+                                rect.origin(),
+                                self.axis.pack(self.axis.major(rect.size()), minor),
+                            ),
+                        );
+                    }
+                }
+            }
+        }
+        self.scroll_to_selected = false;
+
+        let wanted = self.axis.pack(f64::max(major, viewport_major), minor);
         bc.constrain(wanted)
     }
 
@@ -401,35 +908,142 @@ impl<TFD: TabsFromData> Widget<TabsState<TFD>> for TabBar<TFD> {
                 )
             }
         }
+
+        if let Some(add_button) = &mut self.add_button {
+            let rect = add_button.layout_rect();
+            ctx.fill(rect, &env.get(theme::BACKGROUND_DARK));
+            add_button.paint(ctx, data, env);
+        }
+    }
+
+    fn accessibility(&mut self, ctx: &mut AccessCtx, data: &TabsState<TFD>, env: &Env) {
+        let bounds = self
+            .tabs
+            .iter()
+            .map(|(_, tab)| tab.layout_rect())
+            .fold(Rect::ZERO, |acc, r| acc.union(r));
+        ctx.push_node(AccessRole::TabList, bounds);
+        for (idx, (_, tab)) in self.tabs.iter_mut().enumerate() {
+            let rect = tab.layout_rect();
+            ctx.push_node(AccessRole::Tab, rect).enabled = idx == data.selected;
+            tab.accessibility(ctx, data, env);
+        }
+        if let Some(add_button) = &mut self.add_button {
+            let rect = add_button.layout_rect();
+            ctx.push_node(AccessRole::Button, rect).name = Some("Add tab".to_string());
+            add_button.accessibility(ctx, data, env);
+        }
     }
 }
 
-pub struct TabsTransition {
+/// A function mapping a linear `0.0..=1.0` progress fraction to an eased one, for use with
+/// [`TabsTransition::Slide`]/[`TabsTransition::Fade`].
+///
+/// [`TabsTransition::Slide`]: enum.TabsTransition.html#variant.Slide
+/// [`TabsTransition::Fade`]: enum.TabsTransition.html#variant.Fade
+pub type Easing = fn(f64) -> f64;
+
+/// The default easing: a smoothstep curve that starts and ends slowly and moves fastest through
+/// the middle of the transition, rather than the constant speed of a linear fraction.
+pub fn ease_in_out(t: f64) -> f64 {
+    t * t * (3.0 - 2.0 * t)
+}
+
+/// How [`TabsBody`] animates between the previous and newly selected tab's body. The default,
+/// via [`Tabs::with_transition`], is [`TabsTransition::Slide`] over 250ms, eased with
+/// [`ease_in_out`].
+///
+/// [`TabsBody`]: struct.TabsBody.html
+/// [`Tabs::with_transition`]: struct.Tabs.html#method.with_transition
+#[derive(Clone, Copy)]
+pub enum TabsTransition {
+    /// Switch with no animation at all.
+    Instant,
+    /// Slide the previous body out and the selected body in along the tab bar's axis.
+    Slide { duration: u64, easing: Easing },
+    /// Cross-fade the previous body out and the selected body in in place.
+    Fade { duration: u64, easing: Easing },
+}
+
+impl TabsTransition {
+    /// A [`TabsTransition::Slide`] of `duration` nanos, eased with [`ease_in_out`].
+    ///
+    /// [`TabsTransition::Slide`]: enum.TabsTransition.html#variant.Slide
+    pub fn slide(duration: u64) -> Self {
+        TabsTransition::Slide {
+            duration,
+            easing: ease_in_out,
+        }
+    }
+
+    /// A [`TabsTransition::Fade`] of `duration` nanos, eased with [`ease_in_out`].
+    ///
+    /// [`TabsTransition::Fade`]: enum.TabsTransition.html#variant.Fade
+    pub fn fade(duration: u64) -> Self {
+        TabsTransition::Fade {
+            duration,
+            easing: ease_in_out,
+        }
+    }
+}
+
+impl Default for TabsTransition {
+    fn default() -> Self {
+        TabsTransition::slide(250 * MILLIS)
+    }
+}
+
+/// Which [`TabsTransition`] variant an [`ActiveTransition`] is playing out, stripped of the
+/// `duration`/`easing` it was built from (those are captured directly on `ActiveTransition`).
+///
+/// [`TabsTransition`]: enum.TabsTransition.html
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum TransitionKind {
+    Slide,
+    Fade,
+}
+
+/// The in-flight state of a single tab switch: which tab we're animating away from, how far
+/// through the configured [`TabsTransition`] we are, and which direction we're moving.
+///
+/// [`TabsTransition`]: enum.TabsTransition.html
+struct ActiveTransition {
     previous_idx: TabIndex,
     current_time: u64,
     length: u64,
     increasing: bool,
+    kind: TransitionKind,
+    easing: Easing,
 }
 
-impl TabsTransition {
-    pub fn new(previous_idx: TabIndex, length: u64, increasing: bool) -> Self {
-        TabsTransition {
+impl ActiveTransition {
+    fn new(
+        previous_idx: TabIndex,
+        length: u64,
+        increasing: bool,
+        kind: TransitionKind,
+        easing: Easing,
+    ) -> Self {
+        ActiveTransition {
             previous_idx,
             current_time: 0,
             length,
             increasing,
+            kind,
+            easing,
         }
     }
 
-    pub fn live(&self) -> bool {
+    fn live(&self) -> bool {
         self.current_time < self.length
     }
 
-    pub fn fraction(&self) -> f64 {
-        (self.current_time as f64) / (self.length as f64)
+    fn fraction(&self) -> f64 {
+        let linear = (self.current_time as f64) / (self.length as f64);
+        (self.easing)(linear.min(1.0))
     }
 
-    pub fn previous_transform(&self, axis: Axis, main: f64) -> Affine {
+    fn previous_transform(&self, axis: Axis, main: f64) -> Affine {
         let x = if self.increasing {
             -main * self.fraction()
         } else {
@@ -438,7 +1052,7 @@ impl TabsTransition {
         Affine::translate(axis.pack(x, 0.))
     }
 
-    pub fn selected_transform(&self, axis: Axis, main: f64) -> Affine {
+    fn selected_transform(&self, axis: Axis, main: f64) -> Affine {
         let x = if self.increasing {
             main * (1.0 - self.fraction())
         } else {
@@ -446,6 +1060,20 @@ impl TabsTransition {
         };
         Affine::translate(axis.pack(x, 0.))
     }
+
+    /// Layer alpha for the outgoing body under [`TabsTransition::Fade`].
+    ///
+    /// [`TabsTransition::Fade`]: enum.TabsTransition.html#variant.Fade
+    fn previous_alpha(&self) -> f64 {
+        1.0 - self.fraction()
+    }
+
+    /// Layer alpha for the incoming body under [`TabsTransition::Fade`].
+    ///
+    /// [`TabsTransition::Fade`]: enum.TabsTransition.html#variant.Fade
+    fn selected_alpha(&self) -> f64 {
+        self.fraction()
+    }
 }
 
 fn ensure_for_tabs<Content, TFD: TabsFromData + ?Sized>(
@@ -472,7 +1100,8 @@ fn ensure_for_tabs<Content, TFD: TabsFromData + ?Sized>(
 
 pub struct TabsBody<TFD: TabsFromData> {
     children: Vec<(TFD::TabKey, Option<TabBodyPod<TFD>>)>,
-    transition: Option<TabsTransition>,
+    transition_style: TabsTransition,
+    transition: Option<ActiveTransition>,
     axis: Axis,
     phantom_tfd: PhantomData<TFD>,
 }
@@ -481,12 +1110,21 @@ impl<TFD: TabsFromData> TabsBody<TFD> {
     pub fn new(axis: Axis) -> TabsBody<TFD> {
         TabsBody {
             children: vec![],
+            transition_style: TabsTransition::default(),
             transition: None,
             axis,
             phantom_tfd: Default::default(),
         }
     }
 
+    /// Configure how switching between tab bodies is animated. See [`TabsTransition`].
+    ///
+    /// [`TabsTransition`]: enum.TabsTransition.html
+    pub fn with_transition(mut self, transition_style: TabsTransition) -> Self {
+        self.transition_style = transition_style;
+        self
+    }
+
     fn make_tabs(&mut self, data: &TabsState<TFD>, tab_set: TFD::TabSet) -> Vec<usize> {
         ensure_for_tabs(
             &mut self.children,
@@ -494,13 +1132,14 @@ impl<TFD: TabsFromData> TabsBody<TFD> {
             tab_set,
             &data.inner,
             |tfd, key, idx| {
-                tfd.body_from_key(key.clone(), &data.inner).map(WidgetPod::new)
-                    // Make a dummy body
-                    // Box::new(Label::new(format!(
-                    //     "Could not create tab for key {:?} at index {}",
-                    //     key, idx
-                    // )))
-                }
+                tfd.body_from_key(key.clone(), &data.inner)
+                    .map(WidgetPod::new)
+                // Make a dummy body
+                // Box::new(Label::new(format!(
+                //     "Could not create tab for key {:?} at index {}",
+                //     key, idx
+                // )))
+            },
         )
     }
 
@@ -509,11 +1148,14 @@ impl<TFD: TabsFromData> TabsBody<TFD> {
     }
 
     // Doesn't take self to allow separate borrowing
-    fn child(children: &mut Vec<(TFD::TabKey, Option<TabBodyPod<TFD>>)>, idx: usize) -> Option<&mut TabBodyPod<TFD>> {
-        children.get_mut(idx).and_then(|x| x.1.as_mut() )
+    fn child(
+        children: &mut Vec<(TFD::TabKey, Option<TabBodyPod<TFD>>)>,
+        idx: usize,
+    ) -> Option<&mut TabBodyPod<TFD>> {
+        children.get_mut(idx).and_then(|x| x.1.as_mut())
     }
 
-    fn child_pods(&mut self) -> impl Iterator<Item=&mut TabBodyPod<TFD>> {
+    fn child_pods(&mut self) -> impl Iterator<Item = &mut TabBodyPod<TFD>> {
         self.children.iter_mut().flat_map(|x| x.1.as_mut())
     }
 }
@@ -547,13 +1189,7 @@ fn hidden_should_receive_lifecycle(lc: &LifeCycle) -> bool {
 }
 
 impl<TFD: TabsFromData> Widget<TabsState<TFD>> for TabsBody<TFD> {
-    fn event(
-        &mut self,
-        ctx: &mut EventCtx,
-        event: &Event,
-        data: &mut TabsState<TFD>,
-        env: &Env,
-    ) {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut TabsState<TFD>, env: &Env) {
         if hidden_should_receive_event(event) {
             for child in self.child_pods() {
                 child.event(ctx, event, &mut data.inner, env);
@@ -615,19 +1251,37 @@ impl<TFD: TabsFromData> Widget<TabsState<TFD>> for TabsBody<TFD> {
         };
 
         if old_data.selected != data.selected {
-            self.transition = Some(TabsTransition::new(
-                old_data.selected,
-                250 * MILLIS,
-                old_data.selected < data.selected,
-            ));
+            // With animations disabled, or `TabsTransition::Instant`, jump to the new tab
+            // immediately rather than starting a transition we'd otherwise have to no-op to its
+            // final state frame by frame.
+            let kind_and_params = match self.transition_style {
+                TabsTransition::Instant => None,
+                TabsTransition::Slide { duration, easing } => {
+                    Some((TransitionKind::Slide, duration, easing))
+                }
+                TabsTransition::Fade { duration, easing } => {
+                    Some((TransitionKind::Fade, duration, easing))
+                }
+            };
+            if let (true, Some((kind, duration, easing))) =
+                (env.get(theme::ANIMATIONS_ENABLED), kind_and_params)
+            {
+                self.transition = Some(ActiveTransition::new(
+                    old_data.selected,
+                    duration,
+                    old_data.selected < data.selected,
+                    kind,
+                    easing,
+                ));
+                ctx.request_anim_frame();
+            }
             ctx.request_layout();
-            ctx.request_anim_frame();
         }
 
         // Make sure to only pass events to initialised children
         if let Some(init) = init {
             for idx in init {
-                if let Some(child) = Self::child(&mut self.children,idx) {
+                if let Some(child) = Self::child(&mut self.children, idx) {
                     child.update(ctx, &data.inner, env)
                 }
             }
@@ -650,7 +1304,7 @@ impl<TFD: TabsFromData> Widget<TabsState<TFD>> for TabsBody<TFD> {
             let size = child.layout(ctx, bc, inner, env);
             child.set_layout_rect(ctx, inner, env, Rect::from_origin_size(Point::ORIGIN, size));
             size
-        }else{
+        } else {
             bc.max()
         }
     }
@@ -663,24 +1317,48 @@ impl<TFD: TabsFromData> Widget<TabsState<TFD>> for TabsBody<TFD> {
             ctx.clip(Rect::from_origin_size(Point::ZERO, size));
 
             let children = &mut self.children;
-            if let Some(ref mut prev) = Self::child(children, trans.previous_idx) {
-                ctx.with_save(|ctx| {
-                    ctx.transform(trans.previous_transform(axis, major));
-                    prev.paint_raw(ctx, &data.inner, env);
-                })
-            }
-            if let Some(ref mut child) = Self::child(children, data.selected) {
-                ctx.with_save(|ctx| {
-                    ctx.transform(trans.selected_transform(axis, major));
-                    child.paint_raw(ctx, &data.inner, env);
-                })
+            match trans.kind {
+                TransitionKind::Slide => {
+                    if let Some(ref mut prev) = Self::child(children, trans.previous_idx) {
+                        ctx.with_save(|ctx| {
+                            ctx.transform(trans.previous_transform(axis, major));
+                            prev.paint_raw(ctx, &data.inner, env);
+                        })
+                    }
+                    if let Some(ref mut child) = Self::child(children, data.selected) {
+                        ctx.with_save(|ctx| {
+                            ctx.transform(trans.selected_transform(axis, major));
+                            child.paint_raw(ctx, &data.inner, env);
+                        })
+                    }
+                }
+                TransitionKind::Fade => {
+                    if let Some(ref mut prev) = Self::child(children, trans.previous_idx) {
+                        ctx.with_alpha(trans.previous_alpha(), |ctx| {
+                            prev.paint_raw(ctx, &data.inner, env);
+                        })
+                    }
+                    if let Some(ref mut child) = Self::child(children, data.selected) {
+                        ctx.with_alpha(trans.selected_alpha(), |ctx| {
+                            child.paint_raw(ctx, &data.inner, env);
+                        })
+                    }
+                }
             }
         } else {
-            if let Some(ref mut child) =  Self::child(&mut self.children,data.selected) {
+            if let Some(ref mut child) = Self::child(&mut self.children, data.selected) {
                 child.paint_raw(ctx, &data.inner, env);
             }
         }
     }
+
+    fn accessibility(&mut self, ctx: &mut AccessCtx, data: &TabsState<TFD>, env: &Env) {
+        if let Some(child) = self.active_child(data) {
+            let rect = child.layout_rect();
+            ctx.push_node(AccessRole::TabPanel, rect);
+            child.accessibility(ctx, &data.inner, env);
+        }
+    }
 }
 
 // This only needs to exist to be able to give a reasonable type to the TabScope
@@ -693,7 +1371,7 @@ impl<TFD> TabsScopePolicy<TFD> {
     pub fn new(tabs_from_data: TFD, selected: TabIndex) -> Self {
         Self {
             tabs_from_data,
-            selected
+            selected,
         }
     }
 }
@@ -741,6 +1419,27 @@ impl TabOrientation {
     }
 }
 
+/// How [`TabBar`] should handle its tabs overflowing the space it is given. Set via
+/// [`Tabs::with_overflow`].
+///
+/// [`TabBar`]: struct.TabBar.html
+/// [`Tabs::with_overflow`]: struct.Tabs.html#method.with_overflow
+#[derive(Data, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TabOverflow {
+    /// Lay every tab out at its natural size and let the strip scroll along its axis (via
+    /// [`Event::Wheel`] or the keyboard) once they overflow the viewport. This is the default.
+    Scroll,
+    /// Shrink every tab to an equal share of the viewport once they no longer fit at their
+    /// natural size, rather than scrolling - trades overflow for smaller labels.
+    Compress,
+}
+
+impl Default for TabOverflow {
+    fn default() -> Self {
+        TabOverflow::Scroll
+    }
+}
+
 pub struct InitialTab<T> {
     name: String,
     child: SingleUse<Box<dyn Widget<T>>>, // This is to avoid cloning provided tabs
@@ -772,6 +1471,10 @@ pub struct Tabs<TFD: TabsFromData> {
     axis: Axis,
     cross: CrossAxisAlignment, // Not sure if this should have another enum. Middle means nothing here
     rotation: TabOrientation,
+    show_add_button: bool,
+    closable: bool,
+    overflow: TabOverflow,
+    transition: TabsTransition,
     content: TabsContent<TFD>,
 }
 
@@ -787,6 +1490,10 @@ impl<TFD: TabsFromData> Tabs<TFD> {
             axis: Axis::Horizontal,
             cross: CrossAxisAlignment::Start,
             rotation: TabOrientation::Standard,
+            show_add_button: false,
+            closable: false,
+            overflow: TabOverflow::default(),
+            transition: TabsTransition::default(),
             content,
         }
     }
@@ -795,7 +1502,10 @@ impl<TFD: TabsFromData> Tabs<TFD> {
         Self::with_content(TabsContent::Complete { tabs })
     }
 
-    pub fn building(tabs_from_data: TFD::Build) -> Self where TFD : AddTab {
+    pub fn building(tabs_from_data: TFD::Build) -> Self
+    where
+        TFD: AddTab,
+    {
         Self::with_content(TabsContent::Building {
             tabs: tabs_from_data,
         })
@@ -816,6 +1526,49 @@ impl<TFD: TabsFromData> Tabs<TFD> {
         self
     }
 
+    /// Show a "+" affordance at the end of the tab bar that calls
+    /// [`TabsFromData::add_tab_runtime`] when clicked, letting users create tabs while the app is
+    /// running rather than only at construction time.
+    pub fn with_add_button(mut self, show_add_button: bool) -> Self {
+        self.show_add_button = show_add_button;
+        self
+    }
+
+    /// Give every tab a close ("â“§") glyph, in addition to any individual tab whose
+    /// [`TabInfo::can_close`] already requests one. Clicking it behaves the same as an
+    /// individual closeable tab: it calls [`TabsFromData::close_tab`] and repairs the selection.
+    /// The close glyph occupies its own hit region next to the tab's label, so clicking the
+    /// label still just selects the tab.
+    ///
+    /// [`TabInfo::can_close`]: struct.TabInfo.html#structfield.can_close
+    /// [`TabsFromData::close_tab`]: trait.TabsFromData.html#method.close_tab
+    pub fn with_closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+
+    /// Configure how the tab bar handles its tabs overflowing the space available. See
+    /// [`TabOverflow`]; defaults to [`TabOverflow::Scroll`].
+    ///
+    /// [`TabOverflow`]: enum.TabOverflow.html
+    /// [`TabOverflow::Scroll`]: enum.TabOverflow.html#variant.Scroll
+    pub fn with_overflow(mut self, overflow: TabOverflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Configure how switching between tab bodies is animated. See [`TabsTransition`]; defaults
+    /// to a 250ms [`TabsTransition::Slide`] eased with `ease_in_out`. Pass
+    /// [`TabsTransition::Instant`] to turn animation off entirely.
+    ///
+    /// [`TabsTransition`]: enum.TabsTransition.html
+    /// [`TabsTransition::Slide`]: enum.TabsTransition.html#variant.Slide
+    /// [`TabsTransition::Instant`]: enum.TabsTransition.html#variant.Instant
+    pub fn with_transition(mut self, transition: TabsTransition) -> Self {
+        self.transition = transition;
+        self
+    }
+
     pub fn with_tab(
         mut self,
         name: impl Into<String>,
@@ -847,15 +1600,30 @@ impl<TFD: TabsFromData> Tabs<TFD> {
             axis: self.axis,
             cross: self.cross,
             rotation: self.rotation,
+            show_add_button: self.show_add_button,
+            closable: self.closable,
+            overflow: self.overflow,
+            transition: self.transition,
             content: TabsContent::Complete { tabs },
         }
     }
 
     pub fn make_scope(&self, tabs_from_data: TFD) -> WidgetPod<TFD::T, TabsScope<TFD>> {
         let (bar, body) = (
-            (TabBar::new(self.axis, self.cross, self.rotation), 0.0),
+            (
+                TabBar::new(
+                    self.axis,
+                    self.cross,
+                    self.rotation,
+                    self.show_add_button,
+                    self.closable,
+                    self.overflow,
+                ),
+                0.0,
+            ),
             (
                 TabsBody::new(self.axis)
+                    .with_transition(self.transition)
                     .padding(5.)
                     .border(theme::BORDER_DARK, 0.5)
                     .expand(),
@@ -881,6 +1649,97 @@ impl<TFD: TabsFromData> Tabs<TFD> {
 
 impl<TFD: TabsFromData> Widget<TFD::T> for Tabs<TFD> {
     fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut TFD::T, env: &Env) {
+        if let Event::Command(cmd) = event {
+            if let TabsContent::Running { scope } = &mut self.content {
+                if cmd.is(ADD_TAB) {
+                    scope
+                        .widget_mut()
+                        .batch(ctx, data, env, add_runtime_tab_and_select);
+                    ctx.children_changed();
+                    ctx.set_handled();
+                    return;
+                } else if cmd.is(SELECT_TAB) {
+                    let idx = *cmd.get_unchecked(SELECT_TAB);
+                    scope.widget_mut().batch(ctx, data, env, |state| {
+                        let len = state
+                            .tabs_from_data
+                            .keys_from_set(state.tabs_from_data.tabs(&state.inner), &state.inner)
+                            .len();
+                        if len > 0 {
+                            state.selected = idx.min(len - 1);
+                        }
+                    });
+                    ctx.children_changed();
+                    ctx.set_handled();
+                    return;
+                } else if cmd.is(REMOVE_TAB) {
+                    if let Some(boxed) = cmd.get_unchecked(REMOVE_TAB).take() {
+                        match boxed.downcast::<TFD::TabKey>() {
+                            Ok(key) => {
+                                scope.widget_mut().batch(ctx, data, env, |state| {
+                                    close_tab_and_select(state, *key);
+                                });
+                                ctx.children_changed();
+                            }
+                            Err(_) => log::warn!(
+                                "Received a REMOVE_TAB command whose payload did not match this \
+                                Tabs instance's TabKey type; ignoring."
+                            ),
+                        }
+                    }
+                    ctx.set_handled();
+                    return;
+                } else if cmd.is(SELECT_NEXT_TAB) {
+                    scope
+                        .widget_mut()
+                        .batch(ctx, data, env, |state| move_tab_selection(state, 1));
+                    ctx.set_handled();
+                    return;
+                } else if cmd.is(SELECT_PREV_TAB) {
+                    scope
+                        .widget_mut()
+                        .batch(ctx, data, env, |state| move_tab_selection(state, -1));
+                    ctx.set_handled();
+                    return;
+                }
+            }
+        }
+        if let Event::KeyDown(key_event) = event {
+            // Move selection with Ctrl+Tab/Ctrl+Shift+Tab, and close the selected tab on Ctrl+W
+            // if closable tabs are enabled - the same shortcuts as
+            // [`SELECT_NEXT_TAB`]/[`SELECT_PREV_TAB`]/[`REMOVE_TAB`], bound here so they work as
+            // global cycling shortcuts as soon as focus lands anywhere inside a `Tabs` instance.
+            // Plain arrow keys are deliberately left alone here: they're not modified, so a
+            // focusable descendant in the tab body (a `TextBox`, a list, a slider) needs first
+            // crack at them below; [`TabBar`] is the one place plain arrows switch tabs, and only
+            // once the tab strip itself has focus.
+            //
+            // [`TabBar`]: struct.TabBar.html
+            let dir = match &key_event.key {
+                KbKey::Tab if key_event.mods.ctrl() => {
+                    Some(if key_event.mods.shift() { -1 } else { 1 })
+                }
+                _ => None,
+            };
+            if let Some(dir) = dir {
+                if let TabsContent::Running { scope } = &mut self.content {
+                    scope
+                        .widget_mut()
+                        .batch(ctx, data, env, |state| move_tab_selection(state, dir));
+                    ctx.set_handled();
+                    return;
+                }
+            } else if self.closable
+                && key_event.mods.ctrl()
+                && matches!(&key_event.key, KbKey::Character(c) if c == "w" || c == "W")
+            {
+                if let TabsContent::Running { scope } = &mut self.content {
+                    scope.widget_mut().batch(ctx, data, env, close_selected_tab);
+                    ctx.set_handled();
+                    return;
+                }
+            }
+        }
         if let TabsContent::Running { scope } = &mut self.content {
             scope.event(ctx, event, data, env);
         }
@@ -888,6 +1747,8 @@ impl<TFD: TabsFromData> Widget<TFD::T> for Tabs<TFD> {
 
     fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &TFD::T, env: &Env) {
         if let LifeCycle::WidgetAdded = event {
+            ctx.register_for_focus();
+
             let mut temp = TabsContent::Swapping;
             std::mem::swap(&mut self.content, &mut temp);
 
@@ -918,7 +1779,13 @@ impl<TFD: TabsFromData> Widget<TFD::T> for Tabs<TFD> {
         }
     }
 
-    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &TFD::T, env: &Env) -> Size {
+    fn layout(
+        &mut self,
+        ctx: &mut LayoutCtx,
+        bc: &BoxConstraints,
+        data: &TFD::T,
+        env: &Env,
+    ) -> Size {
         if let TabsContent::Running { scope } = &mut self.content {
             let size = scope.layout(ctx, bc, data, env);
             scope.set_layout_rect(ctx, data, env, Rect::from_origin_size(Point::ORIGIN, size));
@@ -933,4 +1800,10 @@ impl<TFD: TabsFromData> Widget<TFD::T> for Tabs<TFD> {
             scope.paint(ctx, data, env)
         }
     }
+
+    fn accessibility(&mut self, ctx: &mut AccessCtx, data: &TFD::T, env: &Env) {
+        if let TabsContent::Running { scope } = &mut self.content {
+            scope.accessibility(ctx, data, env)
+        }
+    }
 }
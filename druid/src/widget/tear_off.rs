@@ -0,0 +1,231 @@
+// Copyright 2020 The Druid Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A widget that can be dragged out of its window into a standalone one ("torn off", like a
+//! detachable panel or a menu someone pins open) and dragged back in ("redocked"), built on
+//! [`SubWindowRequirement`]/[`SubWindowHost`].
+//!
+//! The docked and torn-off content are different widget instances - one lives in this window,
+//! the other in the sub-window `SubWindowHost` hosts - so [`TearOff`] is given a factory closure
+//! rather than a single child, and builds a fresh instance for whichever window currently shows
+//! the content. Dragging out submits a [`SubWindowRequirement`] the same way any other sub-window
+//! would be requested; once torn off, data flows back through the same
+//! [`SUB_WINDOW_HOST_TO_PARENT`] channel [`SubWindowHost`] already uses for a synced sub-window,
+//! so `TearOff` only has to handle one command rather than invent a parallel mechanism.
+//!
+//! Redocking is driven by [`REDOCK`] rather than by sensing the sub-window's on-screen position
+//! relative to the dock site - that needs the sub-window's screen origin, which isn't available
+//! to a widget in this snapshot. A real redock affordance (a title bar drag, a dock button painted
+//! inside the torn-off content) submits [`REDOCK`] targeting this widget's id; dragging the
+//! *docked* content out is the half of the round trip this file can fully implement.
+//!
+//! [`SubWindowRequirement`]: ../struct.SubWindowRequirement.html
+//! [`SubWindowHost`]: struct.SubWindowHost.html
+//! [`SUB_WINDOW_HOST_TO_PARENT`]: ../commands/constant.SUB_WINDOW_HOST_TO_PARENT.html
+//! [`REDOCK`]: constant.REDOCK.html
+
+use crate::app::WindowConfig;
+use crate::commands::{CLOSE_WINDOW, SUB_WINDOW_HOST_TO_PARENT};
+use crate::lens::Identity;
+use crate::widget::prelude::*;
+use crate::widget::{AccessCtx, AccessRole, AfterLayoutCtx, SubWindowHost};
+use crate::{Data, Point, Rect, Selector, Size, WidgetId, WidgetPod, WindowId};
+
+/// Ask a [`TearOff`] currently shown in its own window to close that window and rejoin its
+/// original spot. See the [module docs][self] for why this - rather than sensing the sub-window's
+/// position - is how redocking is triggered.
+///
+/// [`TearOff`]: struct.TearOff.html
+pub const REDOCK: Selector<()> = Selector::new("druid-builtin.tear-off.redock");
+
+/// How far a drag has to travel, in px, before [`TearOff`] treats it as tearing the content out
+/// into its own window rather than an ordinary click the child widget itself might want to
+/// handle (e.g. a button inside the docked content).
+///
+/// [`TearOff`]: struct.TearOff.html
+const TEAR_OFF_THRESHOLD: f64 = 8.0;
+
+enum TearOffState<T, W> {
+    /// Content is laid out and painted locally, by `self`.
+    Docked(WidgetPod<T, W>),
+    /// Content lives in `SubWindowHost` on the other end of this `WindowId`; this widget has
+    /// nothing of its own to lay out or paint until it's redocked.
+    TornOff(WindowId),
+}
+
+/// A widget that can be torn out of its window into a standalone one, and redocked again. See the
+/// [module docs][self] for the mechanism.
+pub struct TearOff<T, W> {
+    id: WidgetId,
+    make_child: Box<dyn Fn() -> W>,
+    window_config: WindowConfig,
+    state: TearOffState<T, W>,
+    drag_origin: Option<Point>,
+}
+
+impl<T: Data, W: Widget<T> + 'static> TearOff<T, W> {
+    /// Create a `TearOff` that builds its content with `make_child`, called once up front for the
+    /// docked instance, again each time the content is torn out into a new sub-window, and again
+    /// each time it is redocked.
+    pub fn new(make_child: impl Fn() -> W + 'static) -> Self {
+        let make_child = Box::new(make_child);
+        TearOff {
+            id: WidgetId::next(),
+            state: TearOffState::Docked(WidgetPod::new(make_child())),
+            make_child,
+            window_config: WindowConfig::default(),
+            drag_origin: None,
+        }
+    }
+
+    /// Configure the window the content is shown in once torn off. Defaults to
+    /// `WindowConfig::default()`.
+    pub fn with_window_config(mut self, window_config: WindowConfig) -> Self {
+        self.window_config = window_config;
+        self
+    }
+
+    /// Whether the content is currently shown in its own window rather than docked in place.
+    pub fn is_torn_off(&self) -> bool {
+        matches!(self.state, TearOffState::TornOff(_))
+    }
+
+    fn redock(&mut self, ctx: &mut EventCtx) {
+        if let TearOffState::TornOff(window_id) = &self.state {
+            ctx.submit_command(CLOSE_WINDOW.with(()), *window_id);
+            self.state = TearOffState::Docked(WidgetPod::new((self.make_child)()));
+            ctx.children_changed();
+        }
+    }
+}
+
+impl<T: Data, W: Widget<T> + 'static> Widget<T> for TearOff<T, W> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        if let Event::Command(cmd) = event {
+            if cmd.is(REDOCK) {
+                self.redock(ctx);
+                ctx.set_handled();
+                return;
+            }
+            if cmd.is(SUB_WINDOW_HOST_TO_PARENT) {
+                match cmd
+                    .get_unchecked(SUB_WINDOW_HOST_TO_PARENT)
+                    .downcast_ref::<T>()
+                {
+                    Some(update) => *data = update.clone(),
+                    None => log::warn!(
+                        "TearOff received a sub-window-to-parent update that didn't downcast to \
+                         its data type; ignoring it."
+                    ),
+                }
+                ctx.set_handled();
+                return;
+            }
+        }
+
+        let mut tear_off = false;
+        if let TearOffState::Docked(child) = &mut self.state {
+            match event {
+                Event::MouseDown(e) => {
+                    self.drag_origin = Some(e.pos);
+                    ctx.set_active(true);
+                }
+                Event::MouseMove(e) => {
+                    if ctx.is_active() {
+                        if let Some(origin) = self.drag_origin {
+                            if origin.distance(e.pos) > TEAR_OFF_THRESHOLD {
+                                tear_off = true;
+                            }
+                        }
+                    }
+                }
+                Event::MouseUp(_) => {
+                    self.drag_origin = None;
+                    ctx.set_active(false);
+                }
+                _ => {}
+            }
+            if !tear_off {
+                child.event(ctx, event, data, env);
+            }
+        }
+
+        if tear_off {
+            ctx.set_active(false);
+            self.drag_origin = None;
+            let requirement = SubWindowHost::make_requirement_from_lens(
+                self.id,
+                self.window_config.clone(),
+                true,
+                Identity,
+                (self.make_child)(),
+                data.clone(),
+            );
+            self.state = TearOffState::TornOff(requirement.window_id);
+            ctx.new_sub_window(requirement);
+            ctx.children_changed();
+        }
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        if let TearOffState::Docked(child) = &mut self.state {
+            child.lifecycle(ctx, event, data, env);
+        }
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &T, data: &T, env: &Env) {
+        if let TearOffState::Docked(child) = &mut self.state {
+            child.update(ctx, data, env);
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
+        match &mut self.state {
+            TearOffState::Docked(child) => {
+                let size = child.layout(ctx, bc, data, env);
+                child.set_layout_rect(ctx, data, env, Rect::from_origin_size(Point::ORIGIN, size));
+                size
+            }
+            // Nothing local to show while torn off - collapse to nothing, the same as any other
+            // placeholder left behind by content that moved elsewhere.
+            TearOffState::TornOff(_) => bc.constrain(Size::ZERO),
+        }
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
+        if let TearOffState::Docked(child) = &mut self.state {
+            child.paint(ctx, data, env);
+        }
+    }
+
+    fn accessibility(&mut self, ctx: &mut AccessCtx, data: &T, env: &Env) {
+        if let TearOffState::Docked(child) = &mut self.state {
+            ctx.push_node(AccessRole::Generic, child.layout_rect());
+            child.accessibility(ctx, data, env);
+        }
+    }
+
+    // Assumes `Widget::after_layout` defaults to recursing into children, the same as
+    // `accessibility`'s default - see the `after_layout` module docs for the mechanism. While
+    // torn off there's nothing local to register; `layout` already collapsed us to zero size.
+    fn after_layout(&mut self, ctx: &mut AfterLayoutCtx, data: &T, env: &Env) {
+        if let TearOffState::Docked(child) = &mut self.state {
+            child.after_layout(ctx, data, env);
+        }
+    }
+
+    fn id(&self) -> Option<WidgetId> {
+        Some(self.id)
+    }
+}